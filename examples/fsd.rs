@@ -4,7 +4,7 @@ fn main() {
     let entries = parse_journal_dir("dumps/").unwrap();
 
     // Print all FSDJumps to Sol.
-    for entry in entries.iter() {
+    for entry in entries.iter().flatten() {
         if let Event::FsdJump(event) = &entry.event {
             if event.system.name == "Sol" {
                 println!("{}", entry.timestamp);
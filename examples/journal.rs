@@ -5,7 +5,7 @@ fn main() {
         parse_journal_dir(".\\elite_journal\\Elite Dangerous").unwrap();
 
     // Print all FSDJumps to Sol.
-    for entry in entries.iter() {
+    for entry in entries.iter().flatten() {
         if let Event::FsdJump(event) = &entry.event {
             if event.system.name == "Sol" {
                 println!("{}", entry.timestamp);
@@ -88,6 +88,9 @@ impl fmt::Display for AtmosphereType {
 /// their own right: a body can have a surface and no volcanism. They are held
 /// here rather than on [`Body`] to say where they can and cannot be expected,
 /// since none of them means anything without somewhere to stand.
+///
+/// The three read from an empty string are left out when written rather than
+/// written as null, which they would not read back from.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Surface {
@@ -102,13 +105,16 @@ pub struct Surface {
     /// What the atmosphere is called, where [`Surface::atmosphere_type`] is
     /// what it is made of
     #[serde(default, deserialize_with = "de::empty_str_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub atmosphere: Option<String>,
     #[serde(default, deserialize_with = "de::empty_str_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub volcanism: Option<String>,
     #[serde(default, deserialize_with = "de::empty_str_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub terraform_state: Option<String>,
     /// What can be picked up off it, which is nothing unless it is landable
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub materials: Vec<Material>,
 }
 
@@ -129,8 +135,10 @@ pub struct Orbit {
     pub periapsis: f32,
     pub orbital_period: f32,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ascending_node: Option<f32>,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mean_anomaly: Option<f32>,
 }
 
@@ -176,7 +184,7 @@ pub struct Node {
     pub body_id: i16,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Body {
     #[serde(rename = "BodyID")]
@@ -185,15 +193,18 @@ pub struct Body {
     #[serde(alias = "Body")]
     pub name: String,
     #[serde(rename = "BodyType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<BodyType>,
     /// Distance from primary star in light seconds
     #[serde(rename = "DistanceFromArrivalLS")]
     #[serde(alias = "DistFromStarLS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub distance_from_arrival: Option<f32>,
     pub parents: Vec<Map<String, i16>>,
 
     pub planet_class: String, // TODO: e.g. "Rocky body"
     /// [`None`] where the scan does not report it, which a basic one does not
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tidal_lock: Option<bool>,
     /// Body masses in units of earth masses
     #[serde(rename = "MassEM")]
@@ -206,6 +217,7 @@ pub struct Body {
     pub gravity: f32,
     /// [`None`] where the scan was a basic one, which does not report it
     #[serde(rename = "SurfaceTemperature")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
     /// [`None`] for a body with no surface, which is to say a gas giant
     #[serde(flatten)]
@@ -221,7 +233,7 @@ pub struct Body {
     pub discovery: Discovery,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Signal {
     // e.g. Alexandrite
@@ -232,7 +244,7 @@ pub struct Signal {
     pub count: usize,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Star {
     #[serde(rename = "BodyName")]
@@ -240,7 +252,7 @@ pub struct Star {
     #[serde(rename = "BodyID")]
     pub id: i16,
     /// Empty for the primary, which is what everything else is measured from
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub parents: Vec<Map<String, i16>>,
 
     pub absolute_magnitude: f32,
@@ -1,4 +1,6 @@
-use serde::{Deserialize, Deserializer};
+use serde::de::value::MapDeserializer;
+use serde::{de, Deserialize, Deserializer};
+use std::{fmt, iter};
 
 /// Used to describe types which can consider themselves "null"
 ///
@@ -153,3 +155,49 @@ where
         None => Ok(None),
     }
 }
+
+/// The `event` names a tagged enum has a variant for, as its derive lists them
+///
+/// Found by handing it an `event` of `""`, which names no variant, and keeping
+/// the list it refuses that with. [`None`] where nothing was refused, as from
+/// an enum that catches every event, or from anything that is not an enum.
+pub(crate) fn variants<T, F>(deserialize: F) -> Option<&'static [&'static str]>
+where
+    F: FnOnce(NoVariant) -> Result<T, Variants>,
+{
+    deserialize(MapDeserializer::new(iter::once(("event", ""))))
+        .err()?
+        .0
+}
+
+/// An object whose `event` names no variant, for [`variants`]
+pub(crate) type NoVariant = MapDeserializer<
+    'static,
+    iter::Once<(&'static str, &'static str)>,
+    Variants,
+>;
+
+/// Why a [`NoVariant`] did not read, which is only kept if it was its `event`
+#[derive(Debug)]
+pub(crate) struct Variants(Option<&'static [&'static str]>);
+
+impl de::Error for Variants {
+    fn custom<T: fmt::Display>(_: T) -> Self {
+        Variants(None)
+    }
+
+    fn unknown_variant(_: &str, expected: &'static [&'static str]) -> Self {
+        Variants(Some(expected))
+    }
+}
+
+impl fmt::Display for Variants {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(expected) => write!(f, "one of {} variants", expected.len()),
+            None => f.write_str("no variants"),
+        }
+    }
+}
+
+impl std::error::Error for Variants {}
@@ -0,0 +1,216 @@
+//! What is in the hold, and what changed in it
+//!
+//! The game has written the hold two ways. Before 3.3 the `Cargo` journal
+//! event listed it; since then that event says only how much there is, and
+//! the list goes in `Cargo.json` beside the journal, rewritten every time it
+//! changes. A [`Manifest`] is either, and `Cargo.json` is read as an
+//! [`Entry`][crate::entry::Entry] of one.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Which hold it is, since the SRV has one of its own
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum Vessel {
+    #[serde(rename = "SRV")]
+    Srv,
+    Ship,
+}
+
+/// Everything in a hold, a line per commodity
+///
+/// Cargo for a mission is a line of its own, apart from any of the same
+/// commodity carried for trade, so a commodity can appear more than once.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Manifest {
+    pub vessel: Vessel,
+    /// Tonnes aboard, all told
+    ///
+    /// [`None`] from a game older than 3.3. Since then a journal event that
+    /// gives this and no [`Manifest::inventory`] means the list is in
+    /// `Cargo.json`, not that the hold is empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inventory: Vec<Cargo>,
+}
+
+/// One line of a [`Manifest`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Cargo {
+    /// The game's own name for it, lowercase and unspaced
+    pub name: String,
+    /// What the commander's game calls it, where that is not just the name
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub count: u64,
+    /// How many of [`Cargo::count`] are stolen
+    pub stolen: u64,
+    /// The mission it is carried for, where it is
+    ///
+    /// `MissionID`, which PascalCase makes `MissionId` and which the game does
+    /// not send: no mission cargo was ever read as such.
+    #[serde(rename = "MissionID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<u64>,
+}
+
+/// How one line of the hold changed between two manifests
+///
+/// Stolen cargo is counted apart from the rest, since selling it is a
+/// different trip from selling the rest. Counts that went up and went down
+/// are both given, as a line can gain clean cargo and lose stolen cargo
+/// between the same two manifests.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CargoChange {
+    pub name: String,
+    /// The mission it is carried for, which makes it a line of its own
+    pub mission_id: Option<u64>,
+    /// Tonnes more of it, not stolen
+    pub added: u64,
+    /// Tonnes fewer of it, not stolen
+    pub removed: u64,
+    pub stolen_added: u64,
+    pub stolen_removed: u64,
+}
+
+impl Manifest {
+    /// What changed between this and a later manifest of the same hold
+    ///
+    /// A line for each commodity and mission that changed at all, in order of
+    /// name and then mission. What did not change is left out.
+    pub fn diff(&self, after: &Manifest) -> Vec<CargoChange> {
+        // Clean and stolen tonnes, before and after.
+        let mut lines = BTreeMap::<(&str, Option<u64>), [u64; 4]>::new();
+        for (manifest, side) in [(self, 0), (after, 2)] {
+            for cargo in &manifest.inventory {
+                let line =
+                    lines.entry((&cargo.name, cargo.mission_id)).or_default();
+                let stolen = cargo.stolen.min(cargo.count);
+                line[side] += cargo.count - stolen;
+                line[side + 1] += stolen;
+            }
+        }
+
+        lines
+            .into_iter()
+            .filter(|(_, [clean, stolen, clean_after, stolen_after])| {
+                clean != clean_after || stolen != stolen_after
+            })
+            .map(|((name, mission_id), line)| {
+                let [clean, stolen, clean_after, stolen_after] = line;
+                CargoChange {
+                    name: name.to_string(),
+                    mission_id,
+                    added: clean_after.saturating_sub(clean),
+                    removed: clean.saturating_sub(clean_after),
+                    stolen_added: stolen_after.saturating_sub(stolen),
+                    stolen_removed: stolen.saturating_sub(stolen_after),
+                }
+            })
+            .collect()
+    }
+}
+
+/// `Cargo.json` as the game writes it, and two of them told apart
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::read_status_file;
+    use crate::entry::Entry;
+
+    fn manifest(json: &str) -> Manifest {
+        serde_json::from_str::<Entry<Manifest>>(json)
+            .expect("manifest should read")
+            .event
+    }
+
+    #[test]
+    fn cargo_json_reads_through_parse_status_file() {
+        let cargo = read_status_file::<Manifest>(
+            "Cargo.json",
+            r#"{ "timestamp":"2026-08-08T12:00:00Z", "event":"Cargo", "Vessel":"Ship", "Count":9, "Inventory":[
+{ "Name":"gold", "Count":4, "Stolen":0 },
+{ "Name":"hnshockmount", "Name_Localised":"HN Shock Mount", "Count":2, "Stolen":2 },
+{ "Name":"tea", "Count":3, "Stolen":0, "MissionID":880456372 }
+ ] }"#,
+        )
+        .event;
+        assert_eq!(cargo.vessel, Vessel::Ship);
+        assert_eq!(cargo.count, Some(9));
+        assert_eq!(cargo.inventory.len(), 3);
+        assert_eq!(cargo.inventory[0].name_localised, None);
+        assert_eq!(
+            cargo.inventory[1].name_localised.as_deref(),
+            Some("HN Shock Mount")
+        );
+        assert_eq!(cargo.inventory[1].stolen, 2);
+        assert_eq!(cargo.inventory[2].mission_id, Some(880456372));
+    }
+
+    /// The journal event since 3.3, which points at the file
+    #[test]
+    fn a_count_without_an_inventory_is_not_an_empty_hold() {
+        let cargo = manifest(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Cargo",
+                "Vessel": "SRV",
+                "Count": 2
+            }"#,
+        );
+
+        assert_eq!(cargo.vessel, Vessel::Srv);
+        assert_eq!(cargo.count, Some(2));
+        assert!(cargo.inventory.is_empty());
+    }
+
+    /// Sold some gold, scooped some stolen gold, and handed in the tea
+    #[test]
+    fn a_diff_keeps_stolen_and_mission_cargo_apart() {
+        let before = manifest(
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "Vessel": "Ship",
+                "Inventory": [
+                    { "Name": "gold", "Count": 4, "Stolen": 0 },
+                    { "Name": "tea", "Count": 3, "Stolen": 0 },
+                    { "Name": "tea", "Count": 5, "Stolen": 0,
+                      "MissionID": 880456372 }
+                ] }"#,
+        );
+        let after = manifest(
+            r#"{ "timestamp": "2026-08-08T12:10:00Z", "Vessel": "Ship",
+                "Inventory": [
+                    { "Name": "gold", "Count": 3, "Stolen": 2 },
+                    { "Name": "tea", "Count": 3, "Stolen": 0 },
+                    { "Name": "silver", "Count": 1, "Stolen": 0 }
+                ] }"#,
+        );
+
+        assert_eq!(
+            before.diff(&after),
+            [
+                CargoChange {
+                    name: "gold".into(),
+                    removed: 3,
+                    stolen_added: 2,
+                    ..Default::default()
+                },
+                CargoChange {
+                    name: "silver".into(),
+                    added: 1,
+                    ..Default::default()
+                },
+                CargoChange {
+                    name: "tea".into(),
+                    mission_id: Some(880456372),
+                    removed: 5,
+                    ..Default::default()
+                },
+            ]
+        );
+        assert!(after.diff(&after).is_empty());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::entry::{Entry, Event, JournalReader, LineError};
+use crate::Error;
+use chrono::NaiveDateTime;
+use std::cmp::Ordering;
+use std::ffi::OsStr;
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+
+/// A journal file, known by its name
+///
+/// The game has named them two ways. Until 2022 it was
+/// `Journal.YYMMDDHHMMSS.NN.log`; since then it is
+/// `Journal.YYYY-MM-DDTHHMMSS.NN.log`. A directory kept long enough has both,
+/// and neither sorts against the other as text. Read as the time and part they
+/// stand for, they do.
+///
+/// The time is the player's local clock rather than UTC, which is what every
+/// entry inside is stamped in, so it is good for putting files in order and
+/// not for much else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalFile {
+    pub path: PathBuf,
+    /// When the file was started, as its name gives it
+    pub started: NaiveDateTime,
+    /// Which part of a session it is, counted from one
+    pub part: u8,
+}
+
+impl JournalFile {
+    /// Read a journal's name, or [`None`] if it is not named as one
+    pub fn parse<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let path = path.as_ref();
+        let name = path.file_name().and_then(OsStr::to_str)?;
+        let (stamp, part) = name
+            .strip_prefix("Journal.")?
+            .strip_suffix(".log")?
+            .rsplit_once('.')?;
+        let format = if stamp.contains('T') {
+            "%Y-%m-%dT%H%M%S"
+        } else {
+            "%y%m%d%H%M%S"
+        };
+        Some(JournalFile {
+            path: path.to_path_buf(),
+            started: NaiveDateTime::parse_from_str(stamp, format).ok()?,
+            part: part.parse().ok()?,
+        })
+    }
+
+    /// Read it, holding its `Fileheader` to the part it is named for
+    pub fn read(&self) -> Result<JournalReader, Error> {
+        Ok(JournalReader::open(&self.path)?.expect_part(self.part))
+    }
+}
+
+/// Ordered as written: by when the file was started, then by part
+impl Ord for JournalFile {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.started, self.part, &self.path).cmp(&(
+            other.started,
+            other.part,
+            &other.path,
+        ))
+    }
+}
+
+impl PartialOrd for JournalFile {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Every journal in `dir`, in the order the game wrote them
+///
+/// Anything not named as a journal is passed over, since there is no telling
+/// where it goes.
+pub fn journal_files<P: AsRef<Path>>(
+    dir: P,
+) -> Result<Vec<JournalFile>, Error> {
+    let dir = dir.as_ref();
+    let mut paths = Vec::new();
+    for entry in read_dir(dir).map_err(|e| Error::io(dir, None, e))? {
+        let entry = entry.map_err(|e| Error::io(dir, None, e))?;
+        let file_type = entry
+            .file_type()
+            .map_err(|e| Error::io(entry.path(), None, e))?;
+        if file_type.is_file() {
+            paths.push(entry.path());
+        }
+    }
+    Ok(in_order(paths))
+}
+
+/// The journals among the files at `paths`, in the order the game wrote them
+///
+/// What [`journal_files`] makes of a directory once it is listed, however it
+/// was listed.
+pub(crate) fn in_order<I>(paths: I) -> Vec<JournalFile>
+where
+    I: IntoIterator<Item = PathBuf>,
+{
+    let mut files: Vec<_> =
+        paths.into_iter().filter_map(JournalFile::parse).collect();
+    files.sort();
+    files
+}
+
+/// One run of the game, from starting it to quitting, however many files long
+///
+/// A session is started by a part one, and every part after it carries on the
+/// same session until the next part one.
+#[derive(Debug)]
+pub struct Session {
+    /// The files it was written across, in order
+    pub files: Vec<JournalFile>,
+    pub entries: Vec<Result<Entry<Event>, LineError>>,
+}
+
+impl Session {
+    /// When it was started, as the name of its first file gives it
+    pub fn started(&self) -> NaiveDateTime {
+        self.files[0].started
+    }
+}
+
+/// Parse all journal files in a directory, a game session at a time
+///
+/// A part with no part one before it, whose start fell out of the directory,
+/// is a session of its own.
+pub fn parse_journal_sessions<P: AsRef<Path>>(
+    dir: P,
+) -> Result<Vec<Session>, Error> {
+    let mut sessions: Vec<Session> = Vec::new();
+    for file in journal_files(dir)? {
+        let mut entries = file.read()?.collect();
+        match sessions.last_mut() {
+            Some(session) if file.part != 1 => {
+                session.entries.append(&mut entries);
+                session.files.push(file);
+            }
+            _ => sessions.push(Session {
+                files: vec![file],
+                entries,
+            }),
+        }
+    }
+    Ok(sessions)
+}
+
+/// A directory of journals named both ways, written in no particular order
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::parse_journal_dir;
+    use crate::entry::testing::{header, scratch_dir};
+    use std::fs;
+
+    /// A journal with its header and one line after it
+    fn journal(dir: &Path, name: &str, part: u8, timestamp: &str) {
+        let music = format!(
+            r#"{{ "timestamp": "{}", "event": "Music", "MusicTrack": "MainMenu" }}"#,
+            timestamp
+        );
+        fs::write(dir.join(name), header(part, timestamp) + &music + "\n")
+            .expect("journal should write");
+    }
+
+    fn timestamps(entries: &[Result<Entry<Event>, LineError>]) -> Vec<String> {
+        entries
+            .iter()
+            .filter_map(|entry| entry.as_ref().ok())
+            .filter(|entry| matches!(entry.event, Event::Fileheader(_)))
+            .map(|entry| entry.timestamp.to_rfc3339())
+            .collect()
+    }
+
+    #[test]
+    fn both_names_read() {
+        let old = JournalFile::parse("Journal.170101120000.01.log")
+            .expect("the old name should read");
+        assert_eq!(old.part, 1);
+        assert_eq!(old.started.to_string(), "2017-01-01 12:00:00");
+
+        let new = JournalFile::parse("logs/Journal.2022-11-30T201500.02.log")
+            .expect("the new name should read");
+        assert_eq!(new.part, 2);
+        assert_eq!(new.started.to_string(), "2022-11-30 20:15:00");
+
+        assert!(old.started < new.started);
+        assert!(JournalFile::parse("Journal.log").is_none());
+        assert!(JournalFile::parse("Status.json").is_none());
+        assert!(JournalFile::parse("netLog.170101120000.01.log").is_none());
+    }
+
+    /// Read in the order written, whatever order the directory lists them in
+    #[test]
+    fn a_directory_reads_in_the_order_it_was_written() {
+        let dir = scratch_dir("dir-order");
+        journal(
+            &dir,
+            "Journal.2022-11-30T201500.01.log",
+            1,
+            "2022-11-30T20:15:00Z",
+        );
+        journal(
+            &dir,
+            "Journal.170101120000.02.log",
+            2,
+            "2017-01-01T14:00:00Z",
+        );
+        journal(
+            &dir,
+            "Journal.170101120000.01.log",
+            1,
+            "2017-01-01T12:00:00Z",
+        );
+        fs::write(dir.join("notes.log"), "not a journal\n").unwrap();
+
+        let entries = parse_journal_dir(&dir).expect("directory should read");
+        assert_eq!(
+            timestamps(&entries),
+            [
+                "2017-01-01T12:00:00+00:00",
+                "2017-01-01T14:00:00+00:00",
+                "2022-11-30T20:15:00+00:00",
+            ]
+        );
+        assert!(entries.iter().all(Result::is_ok));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    /// A file whose header disagrees with its name says so
+    #[test]
+    fn a_header_giving_another_part_is_reported() {
+        let dir = scratch_dir("dir-part");
+        journal(
+            &dir,
+            "Journal.170101120000.01.log",
+            1,
+            "2017-01-01T12:00:00Z",
+        );
+        journal(
+            &dir,
+            "Journal.170101120000.02.log",
+            3,
+            "2017-01-01T14:00:00Z",
+        );
+
+        let entries = parse_journal_dir(&dir).expect("directory should read");
+        let Some(Err(wrong)) = entries.get(2) else {
+            panic!("a header giving the wrong part should not read")
+        };
+        assert_eq!(wrong.line, 1);
+        assert!(matches!(
+            wrong.error,
+            Error::Part {
+                named: 2,
+                header: 3,
+                ..
+            }
+        ));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    /// Parts carry a session on, and a part one starts the next
+    #[test]
+    fn sessions_are_told_apart_by_their_part_ones() {
+        let dir = scratch_dir("dir-sessions");
+        journal(
+            &dir,
+            "Journal.170101120000.01.log",
+            1,
+            "2017-01-01T12:00:00Z",
+        );
+        journal(
+            &dir,
+            "Journal.170101140000.02.log",
+            2,
+            "2017-01-01T14:00:00Z",
+        );
+        journal(
+            &dir,
+            "Journal.2022-11-30T201500.01.log",
+            1,
+            "2022-11-30T20:15:00Z",
+        );
+
+        let sessions =
+            parse_journal_sessions(&dir).expect("directory should read");
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].files.len(), 2);
+        assert_eq!(sessions[0].entries.len(), 4);
+        assert_eq!(sessions[0].started().to_string(), "2017-01-01 12:00:00");
+        assert_eq!(sessions[1].files.len(), 1);
+        assert_eq!(
+            timestamps(&sessions[1].entries),
+            ["2022-11-30T20:15:00+00:00"]
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,62 @@
+//! What a fleet carrier's bartender has to trade
+//!
+//! The `FCMaterials` journal event says the bar was visited, and the stock
+//! goes in `FCMaterials.json`, which is read as an
+//! [`Entry`][crate::entry::Entry] of the same [`FcMaterials`].
+
+use serde::{Deserialize, Serialize};
+
+/// `FCMaterials.json`, or the `FCMaterials` event without its items
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FcMaterials {
+    /// The carrier's, as for any other station
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub carrier_name: String,
+    /// The callsign, e.g. `K7Q-BQL`
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<FcMaterial>,
+}
+
+/// One thing the bartender buys or sells
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FcMaterial {
+    #[serde(rename = "id")]
+    pub id: i64,
+    /// Symbolic, e.g. `$aerogel_name;`
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub price: u64,
+    /// How many the bartender will sell
+    pub stock: u64,
+    /// How many the bartender will buy
+    pub demand: u64,
+}
+
+/// `FCMaterials.json` as the game writes it
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::read_status_file;
+
+    #[test]
+    fn fc_materials_json_reads_through_parse_status_file() {
+        let bar = read_status_file::<FcMaterials>(
+            "FCMaterials.json",
+            r#"{ "timestamp":"2026-08-08T12:00:00Z", "event":"FCMaterials", "MarketID":3704402432, "CarrierName":"Hand of Zeus", "CarrierID":"K7Q-BQL", "Items":[
+{ "id":128961524, "Name":"$aerogel_name;", "Name_Localised":"Aerogel", "Price":500, "Stock":0, "Demand":12 },
+{ "id":128962547, "Name":"$employeedirectory_name;", "Name_Localised":"Employee Directory", "Price":4500, "Stock":3, "Demand":0 }
+ ] }"#,
+        )
+        .event;
+        assert_eq!(bar.carrier_id, "K7Q-BQL");
+        assert_eq!(bar.items[0].demand, 12);
+        assert_eq!(bar.items[1].stock, 3);
+    }
+}
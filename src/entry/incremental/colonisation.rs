@@ -0,0 +1,205 @@
+//! Systems claimed for colonies, and the stations built up in them
+//!
+//! Every station of a colony begins as a construction depot, a
+//! [`StationType::SpaceConstructionDepot`][crate::station::StationType] or
+//! its planetary twin, which is docked at and delivered to like any other.
+//! Its `MarketID` is the station's for good, and is what
+//! [`ConstructionDepots`] keeps each by.
+
+use crate::entry::incremental::{Event, Fold};
+use crate::entry::Entry;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A system claimed, a claim given up, or the beacon placed that makes a
+/// claim good
+///
+/// `ColonisationSystemClaim`, `ColonisationSystemClaimRelease` and
+/// `ColonisationBeaconDeployed` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ColonisationSystem {
+    pub star_system: String,
+    pub system_address: i64,
+}
+
+/// What a construction depot needs and has been given, written on docking and
+/// as deliveries are made
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ColonisationConstructionDepot {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    /// From 0 to 1
+    pub construction_progress: f64,
+    pub construction_complete: bool,
+    pub construction_failed: bool,
+    pub resources_required: Vec<ResourceRequired>,
+}
+
+/// One commodity a [`ColonisationConstructionDepot`] needs
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceRequired {
+    /// Symbolic, e.g. `$aluminium_name;`
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    /// Tonnes
+    pub required_amount: u64,
+    pub provided_amount: u64,
+    /// Credits paid for each tonne delivered
+    pub payment: u64,
+}
+
+impl ResourceRequired {
+    /// Tonnes still to be delivered
+    pub fn remaining(&self) -> u64 {
+        self.required_amount.saturating_sub(self.provided_amount)
+    }
+}
+
+/// Cargo handed over to a construction depot
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ColonisationContribution {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub contributions: Vec<Contribution>,
+}
+
+/// One commodity in a [`ColonisationContribution`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Contribution {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    /// Tonnes
+    pub amount: u64,
+}
+
+/// Every construction depot seen, and what each still needs
+///
+/// A depot is only known once it has been docked at. After that, each
+/// contribution is counted against it until the game next writes the depot
+/// out whole, which then stands as it is, other commanders' deliveries
+/// included.
+#[derive(Debug, Default)]
+pub struct ConstructionDepots {
+    depots: BTreeMap<i64, ColonisationConstructionDepot>,
+}
+
+impl Fold for ConstructionDepots {
+    /// A depot written out whole replaces what was known of it, and cargo
+    /// handed over is taken off what it still needs
+    fn apply(&mut self, entry: &Entry<Event>) {
+        match &entry.event {
+            Event::ColonisationConstructionDepot(depot) => {
+                self.depots.insert(depot.market_id, depot.clone());
+            }
+            Event::ColonisationContribution(contribution) => {
+                let depot = match self.depots.get_mut(&contribution.market_id) {
+                    Some(depot) => depot,
+                    None => return,
+                };
+                for given in &contribution.contributions {
+                    if let Some(resource) = depot
+                        .resources_required
+                        .iter_mut()
+                        .find(|r| r.name.eq_ignore_ascii_case(&given.name))
+                    {
+                        resource.provided_amount += given.amount;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ConstructionDepots {
+    pub fn get(
+        &self,
+        market_id: i64,
+    ) -> Option<&ColonisationConstructionDepot> {
+        self.depots.get(&market_id)
+    }
+
+    /// Every depot seen, by `MarketID`
+    pub fn depots(
+        &self,
+    ) -> impl Iterator<Item = &ColonisationConstructionDepot> {
+        self.depots.values()
+    }
+
+    /// The commodities a depot still needs, with the tonnes of each
+    ///
+    /// Nothing for a depot finished, failed or never seen.
+    pub fn needed(
+        &self,
+        market_id: i64,
+    ) -> impl Iterator<Item = (&ResourceRequired, u64)> {
+        self.depots
+            .get(&market_id)
+            .filter(|depot| {
+                !depot.construction_complete && !depot.construction_failed
+            })
+            .into_iter()
+            .flat_map(|depot| &depot.resources_required)
+            .map(|resource| (resource, resource.remaining()))
+            .filter(|(_, remaining)| *remaining > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::fold;
+
+    const DEPOT: &str = r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "ColonisationConstructionDepot", "MarketID": 3960950786, "ConstructionProgress": 0.25, "ConstructionComplete": false, "ConstructionFailed": false, "ResourcesRequired": [
+        { "Name": "$aluminium_name;", "Name_Localised": "Aluminium", "RequiredAmount": 500, "ProvidedAmount": 125, "Payment": 3239 },
+        { "Name": "$steel_name;", "Name_Localised": "Steel", "RequiredAmount": 800, "ProvidedAmount": 800, "Payment": 5057 },
+        { "Name": "$cmmcomposite_name;", "Name_Localised": "CMM Composite", "RequiredAmount": 4000, "ProvidedAmount": 1000, "Payment": 6788 }
+    ] }"#;
+
+    #[test]
+    fn what_is_still_needed() {
+        let depots = fold::<ConstructionDepots>(&[
+            DEPOT,
+            r#"{ "timestamp": "2026-08-08T12:05:00Z", "event": "ColonisationContribution", "MarketID": 3960950786, "Contributions": [ { "Name": "$CMMComposite_name;", "Name_Localised": "CMM Composite", "Amount": 720 } ] }"#,
+            // Nobody here has docked at this one.
+            r#"{ "timestamp": "2026-08-08T12:06:00Z", "event": "ColonisationContribution", "MarketID": 3960950999, "Contributions": [ { "Name": "$steel_name;", "Amount": 10 } ] }"#,
+        ]);
+
+        let needed = depots
+            .needed(3960950786)
+            .map(|(resource, remaining)| {
+                (resource.name_localised.as_deref().unwrap(), remaining)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(needed, [("Aluminium", 375), ("CMM Composite", 2280)]);
+        assert_eq!(depots.needed(3960950999).count(), 0);
+        assert_eq!(depots.depots().count(), 1);
+    }
+
+    /// The depot written out again is the truth, and a finished one wants
+    /// nothing
+    #[test]
+    fn a_depot_written_again_stands() {
+        let depots = fold::<ConstructionDepots>(&[
+            DEPOT,
+            r#"{ "timestamp": "2026-08-08T12:05:00Z", "event": "ColonisationContribution", "MarketID": 3960950786, "Contributions": [ { "Name": "$aluminium_name;", "Amount": 100 } ] }"#,
+            r#"{ "timestamp": "2026-08-09T12:00:00Z", "event": "ColonisationConstructionDepot", "MarketID": 3960950786, "ConstructionProgress": 1.0, "ConstructionComplete": true, "ConstructionFailed": false, "ResourcesRequired": [
+                { "Name": "$aluminium_name;", "Name_Localised": "Aluminium", "RequiredAmount": 500, "ProvidedAmount": 500, "Payment": 3239 }
+            ] }"#,
+        ]);
+
+        let depot = depots.get(3960950786).expect("the depot is known");
+        assert!(depot.construction_complete);
+        assert_eq!(depot.resources_required[0].provided_amount, 500);
+        assert_eq!(depots.needed(3960950786).count(), 0);
+    }
+}
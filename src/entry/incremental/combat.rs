@@ -0,0 +1,312 @@
+use crate::entry::incremental::progression::{self, CombatRank};
+use crate::entry::status::LegalState;
+use crate::system::Power;
+use serde::{Deserialize, Serialize};
+
+/// A bounty earned for a kill, paid out later at a station of each faction
+///
+/// A ship's bounty is split among the factions that put one on the pilot, and
+/// comes with a `Rewards` list and a `TotalReward`. A skimmer or an on-foot
+/// target carries a single `Faction` and `Reward` instead, which is what
+/// [`Bounty::total`] and [`Bounty::rewards`] smooth over.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Bounty {
+    /// Symbolic, e.g. `empire_trader` or `Skimmer`
+    pub target: String,
+    #[serde(rename = "Target_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_localised: Option<String>,
+    /// The pilot killed, where the game names them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pilot_name: Option<String>,
+    #[serde(rename = "PilotName_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pilot_name_localised: Option<String>,
+    pub victim_faction: String,
+    #[serde(rename = "VictimFaction_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub victim_faction_localised: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rewards: Vec<BountyReward>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_reward: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward: Option<u64>,
+    /// How many others in the wing the reward was shared with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_with_others: Option<u32>,
+}
+
+impl Bounty {
+    /// Credits earned all told, whichever way the game gave it
+    pub fn total(&self) -> u64 {
+        self.total_reward.or(self.reward).unwrap_or_else(|| {
+            self.rewards.iter().map(|reward| reward.reward).sum()
+        })
+    }
+
+    /// Credits earned from each faction, whichever way the game gave it
+    pub fn rewards(&self) -> Vec<BountyReward> {
+        match (&self.faction, self.reward) {
+            (Some(faction), Some(reward)) if self.rewards.is_empty() => {
+                vec![BountyReward {
+                    faction: faction.clone(),
+                    reward,
+                }]
+            }
+            _ => self.rewards.clone(),
+        }
+    }
+}
+
+/// One faction's share of a [`Bounty`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BountyReward {
+    pub faction: String,
+    pub reward: u64,
+}
+
+/// A combat bond, earned in a conflict zone or against a capital ship
+///
+/// `FactionKillBond` and `CapShipBond` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct KillBond {
+    pub reward: u64,
+    pub awarding_faction: String,
+    #[serde(rename = "AwardingFaction_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub awarding_faction_localised: Option<String>,
+    pub victim_faction: String,
+    #[serde(rename = "VictimFaction_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub victim_faction_localised: Option<String>,
+}
+
+/// The commander's ship destroyed, or the commander killed on foot
+///
+/// One killer is written as `KillerName`, `KillerShip` and `KillerRank`, and a
+/// wing of them as a `Killers` list. Neither is written for a death nobody is
+/// to blame for, such as flying into a star. [`Died::killers`] gives either.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Died {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_name: Option<String>,
+    #[serde(rename = "KillerName_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_name_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_ship: Option<String>,
+    #[serde(default, with = "progression::by_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub killer_rank: Option<CombatRank>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub killers: Vec<Killer>,
+}
+
+impl Died {
+    /// Everyone who had a hand in it, however the game wrote them
+    pub fn killers(&self) -> Vec<Killer> {
+        match &self.killer_name {
+            Some(name) if self.killers.is_empty() => vec![Killer {
+                name: name.clone(),
+                ship: self.killer_ship.clone(),
+                rank: self.killer_rank,
+            }],
+            _ => self.killers.clone(),
+        }
+    }
+}
+
+/// One of a wing that killed the commander
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Killer {
+    pub name: String,
+    /// Symbolic, e.g. `viper`, or a suit for a death on foot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship: Option<String>,
+    /// Written by name, e.g. `Deadly`
+    #[serde(default, with = "progression::by_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<CombatRank>,
+}
+
+/// Back in a ship after a death, and what that cost
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Resurrect {
+    /// e.g. `rebuy`, or `recover` for one that cost nothing
+    pub option: String,
+    pub cost: u64,
+    /// Whether the rebuy was more than there was to pay it with
+    pub bankrupt: bool,
+}
+
+/// Pulled out of supercruise by someone else
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Interdicted {
+    /// Whether the commander gave in rather than fighting it
+    pub submitted: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interdictor: Option<String>,
+    #[serde(rename = "Interdictor_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interdictor_localised: Option<String>,
+    pub is_player: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_thargoid: bool,
+    /// Where the interdictor is a player
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combat_rank: Option<CombatRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<Power>,
+}
+
+/// The commander pulling someone else out of supercruise
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Interdiction {
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interdicted: Option<String>,
+    #[serde(rename = "Interdicted_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interdicted_localised: Option<String>,
+    pub is_player: bool,
+    /// Where the one interdicted is a player
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combat_rank: Option<CombatRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<Power>,
+}
+
+/// Out from under an interdiction without being pulled out
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EscapeInterdiction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interdictor: Option<String>,
+    #[serde(rename = "Interdictor_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interdictor_localised: Option<String>,
+    pub is_player: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_thargoid: bool,
+}
+
+/// Another player killed by the commander
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PvpKill {
+    pub victim: String,
+    pub combat_rank: CombatRank,
+}
+
+/// Shields lost or back up
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShieldState {
+    pub shields_up: bool,
+}
+
+/// The hull damaged, written at each fifth of it lost
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct HullDamage {
+    /// From 0 to 1
+    pub health: f64,
+    /// Whether the commander is flying what was hit
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub player_pilot: bool,
+    /// Whether what was hit is a fighter
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub fighter: bool,
+}
+
+/// Hit, or shot at, by someone
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UnderAttack {
+    pub target: AttackTarget,
+}
+
+/// What was under attack in an [`UnderAttack`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum AttackTarget {
+    /// The commander's own ship, or the commander
+    You,
+    Fighter,
+    /// The ship, while the commander flies a fighter
+    Mothership,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A ship targeted, and as much of it as the scan has got to
+///
+/// The scan comes in stages, each written as it completes. Stage 0 gives the
+/// ship, 1 the pilot and their rank, 2 the shields and hull, and 3 the faction,
+/// legal status, bounty and whichever subsystem is targeted. A target lost
+/// writes only [`ShipTargeted::target_locked`], as `false`.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipTargeted {
+    pub target_locked: bool,
+    /// Symbolic, e.g. `viper_mkiv`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship: Option<String>,
+    #[serde(rename = "Ship_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scan_stage: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pilot_name: Option<String>,
+    #[serde(rename = "PilotName_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pilot_name_localised: Option<String>,
+    /// Written by name, e.g. `Competent`
+    #[serde(default, with = "progression::by_name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pilot_rank: Option<CombatRank>,
+    #[serde(rename = "SquadronID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub squadron_id: Option<String>,
+
+    /// Percent, from 0 to 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shield_health: Option<f64>,
+    /// Percent, from 0 to 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hull_health: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_status: Option<LegalState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bounty: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<Power>,
+    /// Symbolic, e.g. `$int_powerplant_size3_class5_name;`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsystem: Option<String>,
+    #[serde(rename = "Subsystem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsystem_localised: Option<String>,
+    /// Percent, from 0 to 100
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subsystem_health: Option<f64>,
+}
@@ -0,0 +1,444 @@
+//! Materials gathered and spent, and the engineers they are spent with
+//!
+//! The game lists every material the commander holds only on loading a game,
+//! as [`Materials`]. A [`MaterialInventory`] starts from there and follows
+//! each event that adds or takes some away.
+
+use crate::entry::incremental::startup::{Engineering, Material, Materials};
+use crate::entry::incremental::{Event, Fold};
+use crate::entry::Entry;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Which of the three kinds a material is, each kept and capped apart
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaterialCategory {
+    #[serde(alias = "raw", alias = "$MICRORESOURCE_CATEGORY_Raw;")]
+    Raw,
+    #[serde(
+        alias = "manufactured",
+        alias = "$MICRORESOURCE_CATEGORY_Manufactured;"
+    )]
+    Manufactured,
+    #[serde(alias = "encoded", alias = "$MICRORESOURCE_CATEGORY_Encoded;")]
+    Encoded,
+}
+
+/// A material picked up or thrown away
+///
+/// `MaterialCollected` and `MaterialDiscarded` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MaterialCollected {
+    pub category: MaterialCategory,
+    /// The game's own name for it, lowercase and unspaced
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub count: u64,
+}
+
+/// Materials swapped at a material trader
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MaterialTrade {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    /// Which of the three the trader deals in
+    pub trader_type: MaterialCategory,
+    pub paid: TradedMaterial,
+    pub received: TradedMaterial,
+}
+
+/// One side of a [`MaterialTrade`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct TradedMaterial {
+    pub material: String,
+    #[serde(rename = "Material_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub material_localised: Option<String>,
+    pub category: MaterialCategory,
+    pub quantity: u64,
+}
+
+/// A module engineered, or an experimental effect put on it
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EngineerCraft {
+    /// e.g. `MediumHardpoint2`
+    pub slot: String,
+    pub module: String,
+    /// What it cost
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ingredients: Vec<Ingredient>,
+    /// The effect put on, where that is all this was
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apply_experimental_effect: Option<String>,
+    /// What the module is now
+    #[serde(flatten)]
+    pub engineering: Engineering,
+}
+
+/// A material or commodity spent in an [`EngineerCraft`], [`Synthesis`] or
+/// [`TechnologyBroker`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Ingredient {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub count: u64,
+    /// Only given by a [`TechnologyBroker`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<MaterialCategory>,
+}
+
+/// Materials handed over to research, e.g. for a community goal
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScientificResearch {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub category: MaterialCategory,
+    pub count: u64,
+}
+
+/// Something handed to an engineer toward being introduced to them
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EngineerContribution {
+    pub engineer: String,
+    #[serde(rename = "EngineerID")]
+    pub engineer_id: u64,
+    #[serde(rename = "Type")]
+    pub kind: ContributionType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity: Option<String>,
+    #[serde(rename = "Commodity_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub material: Option<String>,
+    #[serde(rename = "Material_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub material_localised: Option<String>,
+    pub quantity: u64,
+    /// Everything handed over so far
+    pub total_quantity: u64,
+}
+
+/// What an [`EngineerContribution`] was of
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ContributionType {
+    Commodity,
+    Materials,
+    Credits,
+    Bond,
+    Bounty,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Where the commander stands with engineers
+///
+/// Written for every engineer at once on loading a game, and for one alone as
+/// that changes.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EngineerProgress {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub engineers: Vec<EngineerStatus>,
+    /// The one engineer, where this is a change
+    #[serde(flatten)]
+    pub engineer: Option<EngineerStatus>,
+}
+
+impl EngineerProgress {
+    /// Every engineer given, however it was written
+    pub fn engineers(&self) -> impl Iterator<Item = &EngineerStatus> {
+        self.engineers.iter().chain(&self.engineer)
+    }
+}
+
+/// One engineer in an [`EngineerProgress`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EngineerStatus {
+    pub engineer: String,
+    #[serde(rename = "EngineerID")]
+    pub engineer_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<EngineerStage>,
+    /// The grade they will engineer up to, once unlocked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank: Option<u8>,
+    /// Percent of the way to the next rank
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rank_progress: Option<u8>,
+}
+
+/// How far a commander has got with an engineer
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineerStage {
+    Known,
+    Invited,
+    Acquainted,
+    Unlocked,
+    Barred,
+}
+
+/// Something made up from materials aboard, e.g. ammunition or FSD injection
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Synthesis {
+    /// e.g. `FSD Basic`
+    pub name: String,
+    pub materials: Vec<Ingredient>,
+}
+
+/// A module unlocked at a technology broker
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct TechnologyBroker {
+    /// e.g. `guardian` or `human`
+    pub broker_type: String,
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub items_unlocked: Vec<UnlockedItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commodities: Vec<Ingredient>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub materials: Vec<Ingredient>,
+}
+
+/// A module made available by a [`TechnologyBroker`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UnlockedItem {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+}
+
+/// How much of every material the commander holds
+///
+/// Names are kept lowercase, as the game is not always sure of the case it
+/// writes them in. Where an event spends a material without saying which kind
+/// it is, it is taken from whichever kind already holds it.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MaterialInventory {
+    pub raw: BTreeMap<String, u64>,
+    pub manufactured: BTreeMap<String, u64>,
+    pub encoded: BTreeMap<String, u64>,
+}
+
+impl From<&Materials> for MaterialInventory {
+    fn from(materials: &Materials) -> Self {
+        fn counts(list: &[Material]) -> BTreeMap<String, u64> {
+            list.iter()
+                .map(|m| (m.name.to_lowercase(), m.count))
+                .collect()
+        }
+        MaterialInventory {
+            raw: counts(&materials.raw),
+            manufactured: counts(&materials.manufactured),
+            encoded: counts(&materials.encoded),
+        }
+    }
+}
+
+impl Fold for MaterialInventory {
+    /// Counts move with materials picked up, thrown away, traded, spent,
+    /// handed over for research and given as a mission's reward
+    ///
+    /// A `Materials` snapshot replaces everything known before it.
+    fn apply(&mut self, entry: &Entry<Event>) {
+        match &entry.event {
+            Event::Materials(materials) => *self = materials.into(),
+            Event::MaterialCollected(collected) => {
+                self.add(collected.category, &collected.name, collected.count)
+            }
+            Event::MaterialDiscarded(discarded) => self.take(
+                Some(discarded.category),
+                &discarded.name,
+                discarded.count,
+            ),
+            Event::ScientificResearch(research) => self.take(
+                Some(research.category),
+                &research.name,
+                research.count,
+            ),
+            Event::MaterialTrade(trade) => {
+                let paid = &trade.paid;
+                self.take(Some(paid.category), &paid.material, paid.quantity);
+                self.add(
+                    trade.received.category,
+                    &trade.received.material,
+                    trade.received.quantity,
+                );
+            }
+            Event::EngineerCraft(EngineerCraft { ingredients, .. })
+            | Event::Synthesis(Synthesis {
+                materials: ingredients,
+                ..
+            })
+            | Event::TechnologyBroker(TechnologyBroker {
+                materials: ingredients,
+                ..
+            }) => {
+                for ingredient in ingredients {
+                    self.take(
+                        ingredient.category,
+                        &ingredient.name,
+                        ingredient.count,
+                    );
+                }
+            }
+            Event::EngineerContribution(EngineerContribution {
+                kind: ContributionType::Materials,
+                material: Some(material),
+                quantity,
+                ..
+            }) => self.take(None, material, *quantity),
+            Event::MissionCompleted(completed) => {
+                for reward in &completed.materials_reward {
+                    let category =
+                        serde_json::from_value(reward.category.as_str().into());
+                    if let Ok(category) = category {
+                        self.add(category, &reward.name, reward.count);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl MaterialInventory {
+    /// What is held of one material, of whichever kind
+    pub fn count(&self, name: &str) -> u64 {
+        let name = name.to_lowercase();
+        [&self.raw, &self.manufactured, &self.encoded]
+            .iter()
+            .find_map(|counts| counts.get(&name).copied())
+            .unwrap_or(0)
+    }
+
+    /// Every material held of one kind, by its name in lowercase
+    pub fn category(
+        &self,
+        category: MaterialCategory,
+    ) -> &BTreeMap<String, u64> {
+        match category {
+            MaterialCategory::Raw => &self.raw,
+            MaterialCategory::Manufactured => &self.manufactured,
+            MaterialCategory::Encoded => &self.encoded,
+        }
+    }
+
+    fn category_mut(
+        &mut self,
+        category: MaterialCategory,
+    ) -> &mut BTreeMap<String, u64> {
+        match category {
+            MaterialCategory::Raw => &mut self.raw,
+            MaterialCategory::Manufactured => &mut self.manufactured,
+            MaterialCategory::Encoded => &mut self.encoded,
+        }
+    }
+
+    fn add(&mut self, category: MaterialCategory, name: &str, count: u64) {
+        let counts = self.category_mut(category);
+        *counts.entry(name.to_lowercase()).or_default() += count;
+    }
+
+    /// Take from the kind given, or else whichever kind holds it
+    fn take(
+        &mut self,
+        category: Option<MaterialCategory>,
+        name: &str,
+        count: u64,
+    ) {
+        let name = name.to_lowercase();
+        let counts = match category {
+            Some(category) => vec![self.category_mut(category)],
+            None => {
+                vec![&mut self.raw, &mut self.manufactured, &mut self.encoded]
+            }
+        };
+        for counts in counts {
+            if let Some(held) = counts.get_mut(&name) {
+                *held = held.saturating_sub(count);
+                if *held == 0 {
+                    counts.remove(&name);
+                }
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::fold;
+
+    const MATERIALS: &str = r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "Materials", "Raw": [ { "Name": "iron", "Count": 50 }, { "Name": "nickel", "Count": 30 } ], "Manufactured": [ { "Name": "hybridcapacitors", "Name_Localised": "Hybrid Capacitors", "Count": 10 } ], "Encoded": [ { "Name": "shielddensityreports", "Name_Localised": "Untypical Shield Scans ", "Count": 20 } ] }"#;
+
+    #[test]
+    fn counts_follow_every_event() {
+        let inventory = fold::<MaterialInventory>(&[
+            MATERIALS,
+            r#"{ "timestamp": "2026-08-08T12:01:00Z", "event": "MaterialCollected", "Category": "Raw", "Name": "iron", "Count": 3 }"#,
+            r#"{ "timestamp": "2026-08-08T12:02:00Z", "event": "MaterialDiscarded", "Category": "Raw", "Name": "nickel", "Count": 30 }"#,
+            r#"{ "timestamp": "2026-08-08T12:03:00Z", "event": "MaterialTrade", "MarketID": 3221397760, "TraderType": "encoded", "Paid": { "Material": "shielddensityreports", "Material_Localised": "Untypical Shield Scans ", "Category": "Encoded", "Quantity": 6 }, "Received": { "Material": "emissiondata", "Material_Localised": "Unexpected Emission Data", "Category": "Encoded", "Quantity": 1 } }"#,
+            r#"{ "timestamp": "2026-08-08T12:04:00Z", "event": "Synthesis", "Name": "FSD Basic", "Materials": [ { "Name": "Iron", "Count": 1 } ] }"#,
+            r#"{ "timestamp": "2026-08-08T12:05:00Z", "event": "EngineerContribution", "Engineer": "Felicity Farseer", "EngineerID": 300100, "Type": "Materials", "Material": "hybridcapacitors", "Material_Localised": "Hybrid Capacitors", "Quantity": 4, "TotalQuantity": 4 }"#,
+            r#"{ "timestamp": "2026-08-08T12:06:00Z", "event": "ScientificResearch", "MarketID": 3228883456, "Name": "iron", "Category": "Raw", "Count": 2 }"#,
+        ]);
+
+        assert_eq!(inventory.count("iron"), 50);
+        assert_eq!(inventory.count("nickel"), 0);
+        assert!(!inventory.raw.contains_key("nickel"), "spent out but kept");
+        assert_eq!(inventory.count("HybridCapacitors"), 6);
+        assert_eq!(inventory.count("shielddensityreports"), 14);
+        assert_eq!(
+            inventory.category(MaterialCategory::Encoded)["emissiondata"],
+            1
+        );
+    }
+
+    /// An event that says which kind it spends takes from that kind alone
+    #[test]
+    fn only_the_kind_named_is_spent() {
+        let inventory = fold::<MaterialInventory>(&[
+            MATERIALS,
+            r#"{ "timestamp": "2026-08-08T12:01:00Z", "event": "MaterialDiscarded", "Category": "Manufactured", "Name": "iron", "Count": 5 }"#,
+            r#"{ "timestamp": "2026-08-08T12:02:00Z", "event": "TechnologyBroker", "BrokerType": "human", "MarketID": 3223529472, "ItemsUnlocked": [], "Materials": [ { "Name": "nickel", "Count": 5, "Category": "Raw" }, { "Name": "hybridcapacitors", "Count": 5, "Category": "Encoded" } ] }"#,
+        ]);
+
+        assert_eq!(inventory.count("iron"), 50);
+        assert_eq!(inventory.count("nickel"), 25);
+        assert_eq!(inventory.count("hybridcapacitors"), 10);
+    }
+
+    /// A snapshot read part way through starts the counts over
+    #[test]
+    fn a_snapshot_starts_over() {
+        let inventory = fold::<MaterialInventory>(&[
+            r#"{ "timestamp": "2026-08-08T11:00:00Z", "event": "MaterialCollected", "Category": "Manufactured", "Name": "gridresistors", "Count": 5 }"#,
+            MATERIALS,
+        ]);
+
+        assert_eq!(inventory.count("gridresistors"), 0);
+        assert_eq!(inventory.raw.len(), 2);
+    }
+}
@@ -10,10 +10,10 @@
 use crate::body::Discovery;
 use crate::prelude::*;
 use chrono::{DateTime, Utc};
-use serde::{de, Deserialize, Deserializer};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::BTreeMap as Map;
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct BuyExplorationData {
     /// System name of purchased data
@@ -22,7 +22,7 @@ pub struct BuyExplorationData {
     pub cost: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct SellExplorationData {
     /// List of system names which were discovered
@@ -40,6 +40,25 @@ pub struct SellExplorationData {
     pub total_earnings: u64,
 }
 
+/// Exploration data sold for several systems at once, which is how every sale
+/// has been written since 3.3
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MultiSellExplorationData {
+    pub discovered: Vec<SoldSystem>,
+    pub base_value: u64,
+    pub bonus: u64,
+    pub total_earnings: u64,
+}
+
+/// One system in a [`MultiSellExplorationData`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SoldSystem {
+    pub system_name: String,
+    pub num_bodies: u64,
+}
+
 pub enum ScanType {
     Basic,
     Detailed,
@@ -49,7 +68,11 @@ pub enum ScanType {
 }
 
 /// What a `Scan` turned out to be about
-#[derive(Debug)]
+///
+/// Written back as the fields of whichever one it is, with nothing to say which
+/// beyond what that one carries, which is what it is told apart by on reading.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(untagged)]
 pub enum ScanTarget {
     Star(Star),
     Body(Body),
@@ -146,7 +169,7 @@ fn carries_only_an_orbit(scan: &serde_json::Value) -> bool {
 /// numbering, going round the body it belongs to, carrying an orbit and nothing
 /// else. It is the orbit that tells it from a belt cluster, which lies in a ring
 /// and has none.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Ring {
     #[serde(rename = "BodyName")]
@@ -154,9 +177,10 @@ pub struct Ring {
     #[serde(rename = "BodyID")]
     pub id: i16,
     /// The body it goes round, nearest first
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub parents: Vec<Map<String, i16>>,
     #[serde(rename = "DistanceFromArrivalLS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub distance_from_arrival: Option<f32>,
     #[serde(flatten)]
     pub orbit: Orbit,
@@ -170,7 +194,7 @@ pub struct Ring {
 /// temperature, because there is no single object there to measure: it is a
 /// stretch of a belt, named for the ring it belongs to and numbered among the
 /// system's bodies.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Cluster {
     #[serde(rename = "BodyName")]
@@ -178,15 +202,16 @@ pub struct Cluster {
     #[serde(rename = "BodyID")]
     pub id: i16,
     /// The ring it lies in, and what that ring goes round
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub parents: Vec<Map<String, i16>>,
     #[serde(rename = "DistanceFromArrivalLS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub distance_from_arrival: Option<f32>,
     #[serde(flatten)]
     pub discovery: Discovery,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Scan {
     /// How close a look was taken, where the sender says
@@ -199,24 +224,78 @@ pub struct Scan {
     #[serde(flatten)]
     pub target: ScanTarget,
 
+    /// Every field of the scan besides the four above, as it was sent
+    ///
+    /// The target's own fields included, since it is read from the same
+    /// fields, along with whatever the target does not read.
     #[serde(flatten)]
     pub other: serde_json::Value,
 }
 
+impl Serialize for Scan {
+    /// Write the scan as it was sent, with whatever was changed in it since
+    ///
+    /// `other` repeats the target's fields as they were first read, so writing
+    /// both out flattened would write those twice. Where the target still
+    /// reads as `other` does, `other` is written alone, which keeps every
+    /// figure to the digits it was sent with rather than the fewer the target
+    /// holds. A target changed since it was read is written over it.
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Read<'a> {
+            scan_type: &'a Option<String>,
+            star_system: &'a str,
+            #[serde(serialize_with = "crate::system::optional_star_pos")]
+            star_pos: &'a Option<Coordinate>,
+            system_address: i64,
+        }
+
+        let mut fields = match &self.other {
+            serde_json::Value::Object(other) => other.clone(),
+            _ => serde_json::Map::new(),
+        };
+        let unchanged = ScanTarget::deserialize(&self.other)
+            .is_ok_and(|target| target == self.target);
+        if !unchanged {
+            let target = serde_json::to_value(&self.target)
+                .map_err(ser::Error::custom)?;
+            if let serde_json::Value::Object(target) = target {
+                fields.extend(target);
+            }
+        }
+        let read = serde_json::to_value(Read {
+            scan_type: &self.scan_type,
+            star_system: &self.star_system,
+            star_pos: &self.star_pos,
+            system_address: self.system_address,
+        })
+        .map_err(ser::Error::custom)?;
+        if let serde_json::Value::Object(read) = read {
+            fields.extend(read);
+        }
+        fields.serialize(s)
+    }
+}
+
 /// Signals read off a body from orbit, which the honk finds
 ///
 /// The same kinds and counts [`SAASignalsFound`] reports, seen from further
 /// off: the honk finds them, a surface scan is what maps them. Either may
 /// arrive first, and either may arrive for a body nothing has scanned.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct FssBodySignals {
     #[serde(rename = "BodyName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub body_name: Option<String>,
     #[serde(rename = "BodyID")]
     pub body_id: i16,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_system: Option<String>,
+    #[serde(serialize_with = "crate::system::optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_pos: Option<Coordinate>,
     pub system_address: i64,
 
@@ -227,7 +306,7 @@ pub struct FssBodySignals {
 ///
 /// Stations, megaships, installations, beacons, and the unidentified sources
 /// that come and go.
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct FssSignalDiscovered {
     pub star_system: Option<String>,
     pub star_pos: Option<Coordinate>,
@@ -280,6 +359,44 @@ impl<'de> Deserialize<'de> for FssSignalDiscovered {
     }
 }
 
+impl Serialize for FssSignalDiscovered {
+    /// Write a lone signal the way the game does, and a batch the way EDDN does
+    ///
+    /// A signal with no stamp of its own was one the game wrote, and goes back
+    /// out with its fields on the event. Anything else is a batch, and each
+    /// signal in it keeps its stamp.
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Shape<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            star_system: &'a Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            #[serde(serialize_with = "crate::system::optional_star_pos")]
+            star_pos: &'a Option<Coordinate>,
+            system_address: i64,
+            #[serde(flatten)]
+            lone: Option<&'a SystemSignal>,
+            #[serde(rename = "signals")]
+            #[serde(skip_serializing_if = "Option::is_none")]
+            batch: Option<&'a [SystemSignal]>,
+        }
+
+        let (lone, batch) = match self.signals.as_slice() {
+            [one] if one.timestamp.is_none() => (Some(one), None),
+            signals => (None, Some(signals)),
+        };
+        Shape {
+            star_system: &self.star_system,
+            star_pos: &self.star_pos,
+            system_address: self.system_address,
+            lone,
+            batch,
+        }
+        .serialize(s)
+    }
+}
+
 /// One signal out of an [`FssSignalDiscovered`] batch
 ///
 /// Only the name is certain. What kind of thing it is, who spawned it and how
@@ -289,7 +406,7 @@ impl<'de> Deserialize<'de> for FssSignalDiscovered {
 /// How long it has left is never told. The journal carries it and the schema
 /// disallows it, so a signal that has since despawned is indistinguishable
 /// from one still there apart from how long ago this was sent.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct SystemSignal {
     /// When it was seen, where the signal was one of a batch
@@ -299,17 +416,26 @@ pub struct SystemSignal {
     /// for a signal the game wrote on its own, which is stamped by the entry
     /// carrying it.
     #[serde(rename = "timestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<DateTime<Utc>>,
     pub signal_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub signal_type: Option<String>,
     /// Permanent where [`Some(true)`], which is as near an expiry as there is
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub is_station: Option<bool>,
     #[serde(rename = "USSType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub uss_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub spawning_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub spawning_faction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub spawning_power: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub opposing_power: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub threat_level: Option<i32>,
 }
 
@@ -319,27 +445,38 @@ pub struct SystemSignal {
 /// was first to it is not here and cannot be -- the schema disallows it as
 /// personal data -- so this says a thing was found, not that it was
 /// discovered.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct CodexEntry {
     #[serde(rename = "System")]
     pub system_name: String,
+    #[serde(serialize_with = "crate::system::optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_pos: Option<Coordinate>,
     pub system_address: i64,
 
     #[serde(rename = "EntryID")]
     pub entry_id: i64,
     /// Not required by the schema, though always sent in practice
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sub_category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub region: Option<String>,
 
     #[serde(rename = "BodyID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub body_id: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub body_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub nearest_destination: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub longitude: Option<f64>,
 }
 
@@ -351,11 +488,13 @@ pub struct CodexEntry {
 /// Names the system `SystemName` where nearly every other event calls it
 /// `StarSystem`. It is not a mistake in the schema, and a struct that assumes
 /// otherwise reads nothing.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct FssDiscoveryScan {
     #[serde(rename = "SystemName")]
     pub system_name: String,
+    #[serde(serialize_with = "crate::system::optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_pos: Option<Coordinate>,
     pub system_address: i64,
 
@@ -375,11 +514,13 @@ pub struct FssDiscoveryScan {
 /// is the tally once every one of them has been resolved.
 ///
 /// Names the system `SystemName`, as [`FssDiscoveryScan`] does.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct FssAllBodiesFound {
     #[serde(rename = "SystemName")]
     pub system_name: String,
+    #[serde(serialize_with = "crate::system::optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_pos: Option<Coordinate>,
     pub system_address: i64,
 
@@ -392,10 +533,13 @@ pub struct FssAllBodiesFound {
 /// The count is the same quantity [`FssDiscoveryScan`] reports, arrived at by
 /// reading a beacon rather than by honking. Unlike those two this event names
 /// the system `StarSystem`, as most events do.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct NavBeaconScan {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_system: Option<String>,
+    #[serde(serialize_with = "crate::system::optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_pos: Option<Coordinate>,
     pub system_address: i64,
 
@@ -408,10 +552,12 @@ pub struct NavBeaconScan {
 ///
 /// [`None`] for the orbit where the barycenter goes round nothing, which is
 /// what the one at the root of a multi-star system comes back as.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct ScanBaryCentre {
     pub star_system: String,
+    #[serde(serialize_with = "crate::system::optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_pos: Option<Coordinate>,
     pub system_address: i64,
     #[serde(rename = "BodyID")]
@@ -420,7 +566,7 @@ pub struct ScanBaryCentre {
     pub orbit: Option<Orbit>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct SAASignalsFound {
     /// The body the surface scan was of, named and numbered
@@ -435,7 +581,10 @@ pub struct SAASignalsFound {
     #[serde(rename = "BodyID")]
     pub body_id: i16,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_system: Option<String>,
+    #[serde(serialize_with = "crate::system::optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_pos: Option<Coordinate>,
     pub system_address: i64,
 
@@ -0,0 +1,296 @@
+//! A fleet carrier as its owner sees it
+//!
+//! Every event here names the carrier by its `CarrierID`, which is the number
+//! the carrier goes by as a station: its
+//! [`Station::market_id`][crate::station::Station::market_id] when docked at,
+//! and in a [`CarrierJump`][super::travel::CarrierJump]. That is how the two
+//! are matched up.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Everything about a carrier, written on opening its management screen
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierStats {
+    /// The carrier's, as its
+    /// [`Station::market_id`][crate::station::Station::market_id]
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    /// e.g. `K7Q-BQL`
+    pub callsign: String,
+    pub name: String,
+    pub docking_access: DockingAccess,
+    pub allow_notorious: bool,
+    /// Tonnes of tritium in the tank
+    pub fuel_level: u64,
+    /// Light years
+    pub jump_range_curr: f64,
+    pub jump_range_max: f64,
+    pub pending_decommission: bool,
+    pub space_usage: SpaceUsage,
+    pub finance: CarrierFinance,
+    pub crew: Vec<CarrierCrew>,
+    pub ship_packs: Vec<CarrierPack>,
+    pub module_packs: Vec<CarrierPack>,
+}
+
+/// Who may dock at a carrier
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DockingAccess {
+    All,
+    None,
+    Friends,
+    Squadron,
+    SquadronFriends,
+}
+
+/// A carrier's hold, in tonnes, and what takes it up
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SpaceUsage {
+    pub total_capacity: u64,
+    pub crew: u64,
+    pub cargo: u64,
+    /// Set aside for buy orders not yet filled
+    pub cargo_space_reserved: u64,
+    pub ship_packs: u64,
+    pub module_packs: u64,
+    pub free_space: u64,
+}
+
+/// A carrier's money, and what its services charge
+///
+/// Part of [`CarrierStats`], and the whole of the `CarrierFinance` event. The
+/// tax rates are left out for a service the carrier does not offer.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierFinance {
+    /// Only in the `CarrierFinance` event
+    #[serde(rename = "CarrierID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub carrier_id: Option<i64>,
+    pub carrier_balance: i64,
+    /// Held back for upkeep
+    pub reserve_balance: i64,
+    pub available_balance: i64,
+    /// Percent of the balance held back
+    pub reserve_percent: f64,
+    /// Percent, on every service, in the `CarrierFinance` event of old
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate: Option<f64>,
+    #[serde(rename = "TaxRate_rearm")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate_rearm: Option<f64>,
+    #[serde(rename = "TaxRate_refuel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate_refuel: Option<f64>,
+    #[serde(rename = "TaxRate_repair")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate_repair: Option<f64>,
+    #[serde(rename = "TaxRate_pioneersupplies")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate_pioneer_supplies: Option<f64>,
+    #[serde(rename = "TaxRate_shipyard")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate_shipyard: Option<f64>,
+    #[serde(rename = "TaxRate_outfitting")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_rate_outfitting: Option<f64>,
+}
+
+/// One service aboard a carrier, and who runs it
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierCrew {
+    /// e.g. `Refuel`, `Shipyard` or `BlackMarket`
+    pub crew_role: String,
+    /// Whether it has ever been paid for
+    pub activated: bool,
+    /// Whether it is running now, where it was ever paid for
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crew_name: Option<String>,
+}
+
+/// A ship or module pack installed in a carrier
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierPack {
+    pub pack_theme: String,
+    pub pack_tier: u8,
+}
+
+/// A jump scheduled
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierJumpRequest {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    pub system_name: String,
+    pub system_address: i64,
+    /// The body it will orbit, where it is not the main star
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(rename = "BodyID")]
+    pub body_id: i16,
+    /// When it goes, where the game said
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub departure_time: Option<DateTime<Utc>>,
+}
+
+/// A scheduled jump called off
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierJumpCancelled {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+}
+
+/// A carrier bought
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierBuy {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    /// Where it was bought, as a market
+    pub bought_at_market: i64,
+    /// The system it was bought in
+    pub location: String,
+    pub system_address: i64,
+    pub price: u64,
+    pub variant: String,
+    pub callsign: String,
+}
+
+/// A carrier put up for scrapping
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierDecommission {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    pub scrap_refund: u64,
+    /// When it will be scrapped, in seconds since the Unix epoch
+    pub scrap_time: i64,
+}
+
+/// Credits moved between the commander and a carrier
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierBankTransfer {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdraw: Option<u64>,
+    /// The commander's credits afterwards
+    pub player_balance: i64,
+    pub carrier_balance: i64,
+}
+
+/// Tritium put in a carrier's tank
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierDepositFuel {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    /// Tonnes put in
+    pub amount: u64,
+    /// Tonnes in the tank afterwards
+    pub total: u64,
+}
+
+/// A service aboard a carrier started, stopped or handed to new crew
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierCrewServices {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    pub operation: CrewOperation,
+    pub crew_role: String,
+    pub crew_name: String,
+}
+
+/// What was done in a [`CarrierCrewServices`]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrewOperation {
+    Activate,
+    Deactivate,
+    Pause,
+    Resume,
+    Replace,
+}
+
+/// A buy or sell order placed, or called off, on a carrier's market
+///
+/// One of [`CarrierTradeOrder::purchase_order`],
+/// [`CarrierTradeOrder::sale_order`] and [`CarrierTradeOrder::cancel_trade`]
+/// is given, saying which it was.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierTradeOrder {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    pub black_market: bool,
+    pub commodity: String,
+    #[serde(rename = "Commodity_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    /// Tonnes wanted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purchase_order: Option<u64>,
+    /// Tonnes offered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sale_order: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancel_trade: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<u64>,
+}
+
+/// Who may dock at a carrier, changed
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierDockingPermission {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    pub docking_access: DockingAccess,
+    pub allow_notorious: bool,
+}
+
+/// A carrier renamed
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierNameChange {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    pub callsign: String,
+    pub name: String,
+}
+
+/// A ship or module pack bought or sold
+///
+/// `CarrierModulePack` and `CarrierShipPack` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarrierPackChange {
+    #[serde(rename = "CarrierID")]
+    pub carrier_id: i64,
+    pub operation: PackOperation,
+    pub pack_theme: String,
+    pub pack_tier: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund: Option<u64>,
+}
+
+/// What was done in a [`CarrierPackChange`]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackOperation {
+    BuyPack,
+    SellPack,
+}
@@ -0,0 +1,457 @@
+//! Missions taken, finished and given up, and those still open
+//!
+//! The game names a mission's faction but never the state it is in, which is
+//! instead spelled out at the end of the mission's name for those offered
+//! because of one: `Mission_Delivery_Boom`, `Mission_Collect_Famine`.
+//! [`MissionAccepted::state`] reads it from there, and the [`MissionLog`]
+//! keeps each open mission's [`Faction`] in that state.
+
+use crate::de::Nullable;
+use crate::entry::incremental::{Event, Fold};
+use crate::entry::Entry;
+use crate::faction::{Faction, State};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A mission taken on
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MissionAccepted {
+    #[serde(rename = "MissionID")]
+    pub mission_id: u64,
+    /// Symbolic, e.g. `Mission_Delivery_Boom`
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localised_name: Option<String>,
+    /// Who gave it
+    pub faction: String,
+    /// Who it is against, or for, where that is someone else
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_faction: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity: Option<String>,
+    #[serde(rename = "Commodity_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type: Option<String>,
+    #[serde(rename = "TargetType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_type_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kill_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passenger_count: Option<u64>,
+    #[serde(rename = "PassengerVIPs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passenger_vips: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passenger_wanted: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passenger_type: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_system: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_station: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_settlement: Option<String>,
+    /// When it fails if not done, where it ever does
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub wing: bool,
+    /// How much it will move the faction's influence, as one to five `+`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub influence: Option<String>,
+    /// How much it will move the faction's regard, as one to five `+`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reputation: Option<String>,
+    /// Credits, where it pays any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward: Option<u64>,
+    /// Credits asked, for a donation mission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donation: Option<String>,
+}
+
+impl MissionAccepted {
+    /// The state the giving faction was in, where the mission's name says
+    pub fn state(&self) -> Option<State> {
+        let last = self.name.trim_end_matches("_name").rsplit('_').next()?;
+        serde_json::from_value::<State>(last.into())
+            .ok()
+            .filter(|state| !state.is_null())
+    }
+}
+
+/// A mission handed in, and what it paid
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MissionCompleted {
+    #[serde(rename = "MissionID")]
+    pub mission_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localised_name: Option<String>,
+    pub faction: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_faction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity: Option<String>,
+    #[serde(rename = "Commodity_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_system: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_station: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_settlement: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reward: Option<u64>,
+    /// Credits given, for a donation mission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donated: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commodity_reward: Vec<CommodityReward>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub materials_reward: Vec<MaterialReward>,
+    #[serde(default)]
+    pub faction_effects: Vec<FactionEffects>,
+}
+
+/// Cargo given as part of a [`MissionCompleted`]'s reward
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CommodityReward {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub count: u64,
+}
+
+/// Materials given as part of a [`MissionCompleted`]'s reward
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MaterialReward {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    /// Symbolic, e.g. `$MICRORESOURCE_CATEGORY_Manufactured;`
+    pub category: String,
+    #[serde(rename = "Category_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_localised: Option<String>,
+    pub count: u64,
+}
+
+/// What a [`MissionCompleted`] did for one faction
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FactionEffects {
+    /// Empty where the effect is on no faction in particular
+    pub faction: String,
+    #[serde(default)]
+    pub effects: Vec<FactionEffect>,
+    /// The faction's influence, in each system it moved in
+    #[serde(default)]
+    pub influence: Vec<InfluenceEffect>,
+    pub reputation_trend: Trend,
+    /// As one to five `+`, or empty
+    pub reputation: String,
+}
+
+/// A change in a faction's fortunes, in words
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FactionEffect {
+    /// Symbolic, e.g. `$MISSIONUTIL_Interaction_Summary_EP_up;`
+    pub effect: String,
+    #[serde(rename = "Effect_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effect_localised: Option<String>,
+    pub trend: Trend,
+}
+
+/// A faction's influence moved in one system
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct InfluenceEffect {
+    pub system_address: i64,
+    pub trend: Trend,
+    /// As one to five `+`
+    pub influence: String,
+}
+
+/// Which way something moved, and whether that is good for the commander
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    UpGood,
+    UpBad,
+    DownGood,
+    DownBad,
+    /// Nothing moved
+    #[serde(rename = "None", alias = "")]
+    None,
+}
+
+/// A mission failed or given up, and any fine for it
+///
+/// `MissionFailed` and `MissionAbandoned` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MissionFailed {
+    #[serde(rename = "MissionID")]
+    pub mission_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localised_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fine: Option<u64>,
+}
+
+/// A mission sent somewhere else, most often back where it came from
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MissionRedirected {
+    #[serde(rename = "MissionID")]
+    pub mission_id: u64,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub localised_name: Option<String>,
+    pub new_destination_station: String,
+    pub new_destination_system: String,
+    pub old_destination_station: String,
+    pub old_destination_system: String,
+}
+
+/// What a community goal paid the commander once it closed
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CommunityGoalReward {
+    #[serde(rename = "CGID")]
+    pub cgid: u64,
+    pub name: String,
+    pub system: String,
+    pub reward: u64,
+}
+
+/// Every mission the commander has, written on loading a game
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Missions {
+    pub active: Vec<MissionStatus>,
+    pub failed: Vec<MissionStatus>,
+    pub complete: Vec<MissionStatus>,
+}
+
+/// One mission in [`Missions`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MissionStatus {
+    #[serde(rename = "MissionID")]
+    pub mission_id: u64,
+    pub name: String,
+    pub passenger_mission: bool,
+    /// Seconds left, from the entry's timestamp
+    pub expires: i64,
+}
+
+/// The missions a commander has open
+///
+/// Taken on with `MissionAccepted`, closed with `MissionCompleted`,
+/// `MissionFailed` or `MissionAbandoned`, and moved by `MissionRedirected`.
+/// The `Missions` snapshot written on loading a game is taken as the truth:
+/// anything it does not list as active is closed, and anything it does that
+/// was taken on before the journals being read is opened with what it says.
+#[derive(Debug, Default)]
+pub struct MissionLog {
+    missions: BTreeMap<u64, ActiveMission>,
+}
+
+/// One open mission in a [`MissionLog`]
+///
+/// A mission known only from a `Missions` snapshot has no more than its name
+/// and expiry.
+#[derive(Debug, PartialEq)]
+pub struct ActiveMission {
+    pub mission_id: u64,
+    pub name: String,
+    pub localised_name: Option<String>,
+    /// Who gave it, in the state the mission's name says they were in
+    pub faction: Option<Faction>,
+    pub destination_system: Option<String>,
+    pub destination_station: Option<String>,
+    pub expiry: Option<DateTime<Utc>>,
+    pub reward: Option<u64>,
+    pub wing: bool,
+}
+
+impl Fold for MissionLog {
+    /// Only a mission's own events change the log, and a `Missions` snapshot
+    /// settles it
+    fn apply(&mut self, entry: &Entry<Event>) {
+        match &entry.event {
+            Event::MissionAccepted(accepted) => {
+                self.missions.insert(
+                    accepted.mission_id,
+                    ActiveMission {
+                        mission_id: accepted.mission_id,
+                        name: accepted.name.clone(),
+                        localised_name: accepted.localised_name.clone(),
+                        faction: Some(Faction {
+                            name: accepted.faction.clone(),
+                            state: accepted.state(),
+                        }),
+                        destination_system: accepted.destination_system.clone(),
+                        destination_station: accepted
+                            .destination_station
+                            .clone()
+                            .or_else(|| {
+                                accepted.destination_settlement.clone()
+                            }),
+                        expiry: accepted.expiry,
+                        reward: accepted.reward,
+                        wing: accepted.wing,
+                    },
+                );
+            }
+            Event::MissionCompleted(MissionCompleted {
+                mission_id, ..
+            })
+            | Event::MissionFailed(MissionFailed { mission_id, .. })
+            | Event::MissionAbandoned(MissionFailed { mission_id, .. }) => {
+                self.missions.remove(mission_id);
+            }
+            Event::MissionRedirected(redirected) => {
+                if let Some(mission) =
+                    self.missions.get_mut(&redirected.mission_id)
+                {
+                    mission.destination_system =
+                        Some(redirected.new_destination_system.clone());
+                    mission.destination_station =
+                        Some(redirected.new_destination_station.clone());
+                }
+            }
+            Event::Missions(missions) => {
+                let mut open = BTreeMap::new();
+                for status in &missions.active {
+                    let mission = self
+                        .missions
+                        .remove(&status.mission_id)
+                        .unwrap_or_else(|| ActiveMission {
+                            mission_id: status.mission_id,
+                            name: status.name.clone(),
+                            localised_name: None,
+                            faction: None,
+                            destination_system: None,
+                            destination_station: None,
+                            expiry: (status.expires > 0).then(|| {
+                                entry.timestamp
+                                    + Duration::seconds(status.expires)
+                            }),
+                            reward: None,
+                            wing: false,
+                        });
+                    open.insert(status.mission_id, mission);
+                }
+                self.missions = open;
+            }
+            _ => {}
+        }
+    }
+}
+
+impl MissionLog {
+    /// Every open mission, by `MissionID`
+    pub fn active(&self) -> impl Iterator<Item = &ActiveMission> {
+        self.missions.values()
+    }
+
+    pub fn get(&self, mission_id: u64) -> Option<&ActiveMission> {
+        self.missions.get(&mission_id)
+    }
+
+    /// Credits still to be earned from every open mission
+    pub fn rewards(&self) -> u64 {
+        self.active().filter_map(|mission| mission.reward).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::fold;
+
+    const BOOM: &str = r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "MissionAccepted", "Faction": "Mother Gaia", "Name": "Mission_Delivery_Boom", "LocalisedName": "Boom time delivery of 20 units of Gold", "Commodity": "$Gold_Name;", "Commodity_Localised": "Gold", "Count": 20, "DestinationSystem": "Diaguandri", "DestinationStation": "Ray Gateway", "Expiry": "2026-08-09T12:00:00Z", "Wing": false, "Influence": "++", "Reputation": "++", "Reward": 1250000, "MissionID": 880456372 }"#;
+    const COURIER: &str = r#"{ "timestamp": "2026-08-08T12:01:00Z", "event": "MissionAccepted", "Faction": "Sol Workers' Party", "Name": "Mission_Courier", "LocalisedName": "Data courier", "DestinationSystem": "Alpha Centauri", "DestinationStation": "Hutton Orbital", "Expiry": "2026-08-10T12:00:00Z", "Wing": false, "Influence": "+", "Reputation": "+", "Reward": 30000, "MissionID": 880456400 }"#;
+
+    #[test]
+    fn missions_open_and_close() {
+        let log = fold::<MissionLog>(&[
+            BOOM,
+            COURIER,
+            r#"{ "timestamp": "2026-08-08T12:02:00Z", "event": "MissionRedirected", "MissionID": 880456400, "Name": "Mission_Courier", "NewDestinationStation": "Abraham Lincoln", "NewDestinationSystem": "Sol", "OldDestinationStation": "Hutton Orbital", "OldDestinationSystem": "Alpha Centauri" }"#,
+            r#"{ "timestamp": "2026-08-08T12:30:00Z", "event": "MissionCompleted", "Faction": "Mother Gaia", "Name": "Mission_Delivery_Boom", "MissionID": 880456372, "Commodity": "$Gold_Name;", "Count": 20, "Reward": 1250000,
+                "MaterialsReward": [ { "Name": "Vanadium", "Category": "$MICRORESOURCE_CATEGORY_Raw;", "Count": 3 } ],
+                "FactionEffects": [ { "Faction": "Mother Gaia", "Effects": [ { "Effect": "$MISSIONUTIL_Interaction_Summary_EP_up;", "Trend": "UpGood" } ], "Influence": [ { "SystemAddress": 3932277478106, "Trend": "UpGood", "Influence": "++" } ], "ReputationTrend": "UpGood", "Reputation": "++" } ] }"#,
+        ]);
+
+        let open = log.active().collect::<Vec<_>>();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].mission_id, 880456400);
+        assert_eq!(open[0].destination_system.as_deref(), Some("Sol"));
+        assert_eq!(log.rewards(), 30000);
+    }
+
+    /// The faction's state is in the mission's name, where it is anywhere
+    #[test]
+    fn the_state_a_mission_was_given_in() {
+        let log = fold::<MissionLog>(&[BOOM, COURIER]);
+
+        let boom = log.get(880456372).expect("the delivery is open");
+        let faction = boom.faction.as_ref().expect("the giver is known");
+        assert_eq!(faction.name, "Mother Gaia");
+        assert_eq!(faction.state, Some(State::Boom));
+
+        let courier = log.get(880456400).expect("the courier is open");
+        assert_eq!(courier.faction.as_ref().unwrap().state, None);
+    }
+
+    /// A reload lists what is open, including what was taken on before
+    #[test]
+    fn the_snapshot_on_loading_is_the_truth() {
+        let log = fold::<MissionLog>(&[
+            BOOM,
+            COURIER,
+            r#"{ "timestamp": "2026-08-08T13:00:00Z", "event": "Missions",
+                "Active": [
+                    { "MissionID": 880456372, "Name": "Mission_Delivery_Boom_name", "PassengerMission": false, "Expires": 82800 },
+                    { "MissionID": 880400000, "Name": "Mission_Salvage_name", "PassengerMission": false, "Expires": 3600 }
+                ],
+                "Failed": [
+                    { "MissionID": 880456400, "Name": "Mission_Courier_name", "PassengerMission": false, "Expires": 0 }
+                ],
+                "Complete": [] }"#,
+        ]);
+
+        assert!(log.get(880456400).is_none(), "a failed mission stayed open");
+        assert_eq!(log.get(880456372).unwrap().reward, Some(1250000));
+        let salvage = log.get(880400000).expect("an older mission was missed");
+        assert_eq!(
+            salvage.expiry,
+            Some("2026-08-08T14:00:00Z".parse().unwrap())
+        );
+    }
+}
@@ -1,35 +1,27 @@
+use crate::entry::cargo::Manifest;
+use crate::entry::fc_materials::FcMaterials;
+use crate::entry::locker::Locker;
+use crate::entry::market::JournalMarket;
+use crate::entry::modules_info::ModulesInfo;
+use crate::entry::outfitting::JournalOutfitting;
 use crate::entry::route::NavRoute;
-use serde::Deserialize;
-
+use crate::entry::shipyard::JournalShipyard;
+use crate::entry::Entry;
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{DeserializeSeed, IntoDeserializer, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::sync::OnceLock;
+
+// Written by the game and not read here yet, so kept as `Event::Unknown`
 // "AfmuRepairs"
-// "Bounty"
-// "BuyAmmo"
-// "BuyDrones"
-// "BuyTradeData"
-// "Cargo"
-// "CargoDepot"
-// "CargoTransfer"
-// "CarrierBankTransfer"
-// "CarrierCrewServices"
-// "CarrierDepositFuel"
-// "CarrierDockingPermission"
-// "CarrierFinance"
-// "CarrierJumpCancelled"
-// "CarrierJumpRequest"
-// "CarrierModulePack"
-// "CarrierNameChange"
-// "CarrierShipPack"
-// "CarrierStats"
-// "CarrierTradeOrder"
 // "ChangeCrewRole"
 // "CockpitBreached"
-// "CollectCargo"
-// "Commander"
 // "CommitCrime"
 // "CommunityGoal"
 // "CommunityGoalDiscard"
 // "CommunityGoalJoin"
-// "CommunityGoalReward"
 // "CrewLaunchFighter"
 // "CrewMemberJoins"
 // "CrewMemberQuits"
@@ -38,124 +30,31 @@ use serde::Deserialize;
 // "DatalinkScan"
 // "DatalinkVoucher"
 // "DataScanned"
-// "Died"
 // "DiscoveryScan"
 // "DockFighter"
-// "DockSRV"
-// "EjectCargo"
 // "EndCrewSession"
-// "EngineerContribution"
-// "EngineerCraft"
-// "EngineerProgress"
-// "EscapeInterdiction"
-// "FactionKillBond"
-// "FetchRemoteModule"
 // "FighterDestroyed"
 // "FighterRebuilt"
-// "Fileheader"
 // "Friends"
-// "FSDJump"
-// "FSDTarget"
-// "FuelScoop"
 // "HeatDamage"
 // "HeatWarning"
-// "HullDamage"
-// "Interdicted"
-// "JetConeBoost"
 // "JoinACrew"
 // "LaunchDrone"
 // "LaunchFighter"
-// "LaunchSRV"
-// "LeaveBody"
-// "Liftoff"
-// "LoadGame"
-// "Loadout"
-// "Location"
-// "Market"
-// "MarketBuy"
-// "MarketSell"
-// "MassModuleStore"
-// "MaterialCollected"
 // "MaterialDiscovered"
-// "Materials"
-// "MaterialTrade"
-// "MiningRefined"
-// "MissionAbandoned"
-// "MissionAccepted"
-// "MissionCompleted"
-// "MissionFailed"
-// "MissionRedirected"
-// "Missions"
-// "ModuleBuy"
-// "ModuleInfo"
-// "ModuleRetrieve"
-// "ModuleSell"
-// "ModuleSellRemote"
-// "ModuleStore"
-// "ModuleSwap"
-// "MultiSellExplorationData"
 // "Music"
-// "NavRoute"
-// "Outfitting"
 // "Passengers"
-// "PayBounties"
-// "PayFines"
-// "Powerplay"
-// "PowerplayCollect"
-// "PowerplayDeliver"
-// "PowerplayFastTrack"
-// "PowerplayJoin"
-// "PowerplayLeave"
-// "PowerplaySalary"
-// "PowerplayVoucher"
-// "Progress"
-// "Promotion"
-// "ProspectedAsteroid"
-// "PVPKill"
 // "QuitACrew"
-// "Rank"
 // "RebootRepair"
 // "ReceiveText"
-// "RedeemVoucher"
-// "RefuelAll"
-// "RefuelPartial"
-// "Repair"
-// "RepairAll"
 // "RepairDrone"
-// "Reputation"
-// "ReservoirReplenished"
-// "RestockVehicle"
-// "Resurrect"
 // "SAAScanComplete"
-// "Scan"
 // "Scanned"
-// "ScientificResearch"
 // "Screenshot"
-// "SellDrones"
-// "SellExplorationData"
 // "SendText"
 // "SetUserShipName"
-// "ShieldState"
-// "ShipTargeted"
-// "Shipyard"
-// "ShipyardBuy"
 // "ShipyardNew"
-// "ShipyardSell"
-// "ShipyardSwap"
-// "ShipyardTransfer"
 // "Shutdown"
-// "SquadronStartup"
-// "SRVDestroyed"
-// "StartJump"
-// "Statistics"
-// "StoredModules"
-// "StoredShips"
-// "SupercruiseEntry"
-// "SupercruiseExit"
-// "Synthesis"
-// "TechnologyBroker"
-// "Touchdown"
-// "UnderAttack"
 // "USSDrop"
 // "VehicleSwitch"
 // "WingAdd"
@@ -164,15 +63,29 @@ use serde::Deserialize;
 // "WingLeave"
 
 /// Information provided by the player journal
-#[derive(Deserialize, Debug)]
-#[serde(tag = "event")]
+///
+/// Read by the derived, tagged dispatch below wherever the `event` names a
+/// variant, and kept whole as [`Event::Unknown`] wherever it does not.
+///
+/// Written back out under the same `event` and field names it was read from,
+/// so a journal read, filtered and written again is still a journal. Compare
+/// what was written, rather than the events, to tell whether two are alike: a
+/// value standing for nothing, such as [`Government::None`], is never equal to
+/// itself, so neither is an event carrying one.
+///
+/// [`Government::None`]: crate::Government::None
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(tag = "event", remote = "Self")]
 pub enum Event {
     Fileheader(startup::Fileheader),
-    Cargo(startup::Manifest),
+    /// What is in the hold, which since 3.3 is listed in
+    /// [`Cargo.json`][crate::entry::cargo] and only counted here
+    Cargo(Manifest),
     NewCommander(startup::NewCommander),
     ClearSavedGame(startup::Commander),
     Commander(startup::Commander),
     LoadGame(startup::LoadGame),
+    Loadout(startup::Loadout),
     Materials(startup::Materials),
 
     Location(travel::Location),
@@ -187,7 +100,25 @@ pub enum Event {
     DockingTimeout(travel::DockingTimeout),
     Undocked(travel::Undocked),
 
+    /// A station's market, shipyard or outfitting bay opened, whose wares are
+    /// in [`Market.json`][crate::entry::market] and the files beside it
+    Market(JournalMarket),
+    Shipyard(JournalShipyard),
+    Outfitting(JournalOutfitting),
+    /// The modules panel looked at, whose modules are in
+    /// [`ModulesInfo.json`][crate::entry::modules_info]
+    ModuleInfo(ModulesInfo),
+    /// What is on foot and what is aboard, which lately are listed in
+    /// [`Backpack.json`][crate::entry::locker] and the ship locker's file
+    Backpack(Locker),
+    ShipLocker(Locker),
+    /// A fleet carrier's bar visited, whose stock is in
+    /// [`FCMaterials.json`][crate::entry::fc_materials]
+    #[serde(rename = "FCMaterials")]
+    FcMaterials(FcMaterials),
+
     Liftoff(travel::Liftoff),
+    Touchdown(travel::Touchdown),
     LeaveBody(travel::LeaveBody),
     ApproachBody(travel::ApproachBody),
     ApproachSettlement(travel::ApproachSettlement),
@@ -197,6 +128,19 @@ pub enum Event {
     #[serde(rename = "FSDJump")]
     FsdJump(travel::FsdJump),
     CarrierJump(travel::CarrierJump),
+    StartJump(travel::StartJump),
+    SupercruiseEntry(travel::SupercruiseEntry),
+    SupercruiseExit(travel::SupercruiseExit),
+    SupercruiseDestinationDrop(travel::SupercruiseDestinationDrop),
+    FuelScoop(travel::FuelScoop),
+    JetConeBoost(travel::JetConeBoost),
+    ReservoirReplenished(travel::ReservoirReplenished),
+    #[serde(rename = "LaunchSRV")]
+    LaunchSrv(travel::LaunchSrv),
+    #[serde(rename = "DockSRV")]
+    DockSrv(travel::Srv),
+    #[serde(rename = "SRVDestroyed")]
+    SrvDestroyed(travel::Srv),
 
     /// A route plotted, whose stops are in
     /// [`NavRoute.json`][crate::entry::route] where the game wrote it
@@ -204,6 +148,7 @@ pub enum Event {
 
     BuyExplorationData(exploration::BuyExplorationData),
     SellExplorationData(exploration::SellExplorationData),
+    MultiSellExplorationData(exploration::MultiSellExplorationData),
     SAASignalsFound(exploration::SAASignalsFound),
     Scan(exploration::Scan),
     ScanBaryCentre(exploration::ScanBaryCentre),
@@ -222,23 +167,358 @@ pub enum Event {
     FssSignalDiscovered(exploration::FssSignalDiscovered),
     CodexEntry(exploration::CodexEntry),
 
-    // TODO: We'll leave this in for sure... but we should test without it, and probably find a way
-    // to add a generic JSON value to this.
-    // https://github.com/serde-rs/serde/issues/1973
-    #[serde(other)]
-    Other,
+    Bounty(combat::Bounty),
+    FactionKillBond(combat::KillBond),
+    CapShipBond(combat::KillBond),
+    Died(combat::Died),
+    Resurrect(combat::Resurrect),
+    Interdicted(combat::Interdicted),
+    Interdiction(combat::Interdiction),
+    EscapeInterdiction(combat::EscapeInterdiction),
+    #[serde(rename = "PVPKill")]
+    PvpKill(combat::PvpKill),
+    ShieldState(combat::ShieldState),
+    HullDamage(combat::HullDamage),
+    UnderAttack(combat::UnderAttack),
+    /// A ship targeted, with more of it each time the scan gets further
+    ShipTargeted(combat::ShipTargeted),
+
+    MarketBuy(trade::MarketBuy),
+    MarketSell(trade::MarketSell),
+    SearchAndRescue(trade::SearchAndRescue),
+    BuyTradeData(trade::BuyTradeData),
+    CollectCargo(trade::CollectCargo),
+    EjectCargo(trade::EjectCargo),
+    CargoTransfer(trade::CargoTransfer),
+    CargoDepot(trade::CargoDepot),
+    MiningRefined(trade::MiningRefined),
+    ProspectedAsteroid(trade::ProspectedAsteroid),
+    AsteroidCracked(trade::AsteroidCracked),
+
+    RefuelAll(station_services::Refuel),
+    RefuelPartial(station_services::Refuel),
+    Repair(station_services::Repair),
+    RepairAll(station_services::RepairAll),
+    RestockVehicle(station_services::RestockVehicle),
+    BuyAmmo(station_services::BuyAmmo),
+    BuyDrones(station_services::BuyDrones),
+    SellDrones(station_services::SellDrones),
+
+    ShipyardBuy(station_services::ShipyardBuy),
+    ShipyardSell(station_services::ShipyardSell),
+    SellShipOnRebuy(station_services::SellShipOnRebuy),
+    ShipyardSwap(station_services::ShipyardSwap),
+    ShipyardTransfer(station_services::ShipyardTransfer),
+    ModuleBuy(station_services::ModuleBuy),
+    ModuleSell(station_services::ModuleSell),
+    ModuleSellRemote(station_services::ModuleSellRemote),
+    ModuleStore(station_services::ModuleStore),
+    ModuleRetrieve(station_services::ModuleRetrieve),
+    ModuleSwap(station_services::ModuleSwap),
+    MassModuleStore(station_services::MassModuleStore),
+    FetchRemoteModule(station_services::FetchRemoteModule),
+    StoredShips(station_services::StoredShips),
+    StoredModules(station_services::StoredModules),
+
+    PayFines(station_services::PayFines),
+    PayBounties(station_services::PayFines),
+    PayLegacyFines(station_services::PayLegacyFines),
+    RedeemVoucher(station_services::RedeemVoucher),
+    CrewHire(station_services::CrewHire),
+    NpcCrewPaidWage(station_services::NpcCrewPaidWage),
+
+    CarrierStats(fleet_carriers::CarrierStats),
+    CarrierJumpRequest(fleet_carriers::CarrierJumpRequest),
+    CarrierJumpCancelled(fleet_carriers::CarrierJumpCancelled),
+    CarrierBuy(fleet_carriers::CarrierBuy),
+    CarrierDecommission(fleet_carriers::CarrierDecommission),
+    CarrierBankTransfer(fleet_carriers::CarrierBankTransfer),
+    CarrierDepositFuel(fleet_carriers::CarrierDepositFuel),
+    CarrierCrewServices(fleet_carriers::CarrierCrewServices),
+    CarrierFinance(fleet_carriers::CarrierFinance),
+    CarrierTradeOrder(fleet_carriers::CarrierTradeOrder),
+    CarrierDockingPermission(fleet_carriers::CarrierDockingPermission),
+    CarrierNameChange(fleet_carriers::CarrierNameChange),
+    CarrierModulePack(fleet_carriers::CarrierPackChange),
+    CarrierShipPack(fleet_carriers::CarrierPackChange),
+
+    Powerplay(powerplay::Powerplay),
+    PowerplayJoin(powerplay::PowerplayJoin),
+    PowerplayLeave(powerplay::PowerplayJoin),
+    PowerplayDefect(powerplay::PowerplayDefect),
+    PowerplayMerits(powerplay::PowerplayMerits),
+    PowerplayRank(powerplay::PowerplayRank),
+    PowerplayCollect(powerplay::PowerplayCargo),
+    PowerplayDeliver(powerplay::PowerplayCargo),
+    PowerplaySalary(powerplay::PowerplaySalary),
+    PowerplayVoucher(powerplay::PowerplayVoucher),
+    PowerplayFastTrack(powerplay::PowerplayFastTrack),
+
+    SquadronStartup(squadrons::SquadronStartup),
+    SquadronCreated(squadrons::Squadron),
+    AppliedToSquadron(squadrons::Squadron),
+    InvitedToSquadron(squadrons::Squadron),
+    JoinedSquadron(squadrons::Squadron),
+    LeftSquadron(squadrons::Squadron),
+    KickedFromSquadron(squadrons::Squadron),
+    DisbandedSquadron(squadrons::Squadron),
+    SquadronDemotion(squadrons::SquadronRankChange),
+    SquadronPromotion(squadrons::SquadronRankChange),
+    SharedBookmarkToSquadron(squadrons::Squadron),
+
+    Missions(missions::Missions),
+    MissionAccepted(missions::MissionAccepted),
+    MissionCompleted(missions::MissionCompleted),
+    MissionFailed(missions::MissionFailed),
+    MissionAbandoned(missions::MissionFailed),
+    MissionRedirected(missions::MissionRedirected),
+    CommunityGoalReward(missions::CommunityGoalReward),
+
+    Embark(on_foot::Embark),
+    Disembark(on_foot::Embark),
+    BookTaxi(on_foot::BookTransport),
+    BookDropship(on_foot::BookTransport),
+    CancelTaxi(on_foot::CancelTransport),
+    CancelDropship(on_foot::CancelTransport),
+    BuySuit(on_foot::Suit),
+    SellSuit(on_foot::Suit),
+    UpgradeSuit(on_foot::UpgradeSuit),
+    CreateSuitLoadout(on_foot::SuitLoadout),
+    SwitchSuitLoadout(on_foot::SuitLoadout),
+    SuitLoadout(on_foot::SuitLoadout),
+    BuyWeapon(on_foot::BuyWeapon),
+    UpgradeWeapon(on_foot::UpgradeWeapon),
+    CollectItems(on_foot::CarriedItem),
+    DropItems(on_foot::CarriedItem),
+    BuyMicroResources(on_foot::MicroResourceSale),
+    SellMicroResources(on_foot::MicroResourceSale),
+    TradeMicroResources(on_foot::TradeMicroResources),
+    TransferMicroResources(on_foot::TransferMicroResources),
+    UseConsumable(on_foot::UseConsumable),
+    ScanOrganic(on_foot::ScanOrganic),
+    SellOrganicData(on_foot::SellOrganicData),
+    BackpackChange(on_foot::BackpackChange),
+
+    MaterialCollected(engineering::MaterialCollected),
+    MaterialDiscarded(engineering::MaterialCollected),
+    MaterialTrade(engineering::MaterialTrade),
+    EngineerCraft(engineering::EngineerCraft),
+    EngineerContribution(engineering::EngineerContribution),
+    EngineerProgress(engineering::EngineerProgress),
+    Synthesis(engineering::Synthesis),
+    TechnologyBroker(engineering::TechnologyBroker),
+    ScientificResearch(engineering::ScientificResearch),
+
+    Rank(progression::Rank),
+    Progress(progression::Progress),
+    Promotion(progression::Promotion),
+    Reputation(progression::Reputation),
+    /// Boxed, as it is many times the size of any other event
+    Statistics(Box<statistics::Statistics>),
+
+    ColonisationSystemClaim(colonisation::ColonisationSystem),
+    ColonisationSystemClaimRelease(colonisation::ColonisationSystem),
+    ColonisationBeaconDeployed(colonisation::ColonisationSystem),
+    ColonisationConstructionDepot(colonisation::ColonisationConstructionDepot),
+    ColonisationContribution(colonisation::ColonisationContribution),
+
+    /// An event nothing here reads yet, with everything it said
+    ///
+    /// `fields` is the rest of the object, less `timestamp` and the two flags
+    /// [`Entry`] takes for itself and less the `event` named beside it. Enough
+    /// to route, count or forward it untouched.
+    #[serde(skip)]
+    Unknown {
+        event: String,
+        fields: serde_json::Value,
+    },
+}
+
+impl Event {
+    /// Every `event` read as a variant of its own, as the game names it
+    ///
+    /// Anything else is [`Event::Unknown`]. Taken from the derived half, so
+    /// there is no second list of them to fall behind the enum. Found once,
+    /// as every line read asks for it.
+    pub fn variants() -> &'static [&'static str] {
+        static VARIANTS: OnceLock<&'static [&'static str]> = OnceLock::new();
+        VARIANTS.get_or_init(|| {
+            crate::de::variants(Event::deserialize)
+                .expect("the derived half is a tagged enum")
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    /// Dispatch on `event`, and keep whatever nothing here answers to
+    ///
+    /// `#[serde(other)]` would do the second half, but only into a unit
+    /// variant, which is where every event not modeled here used to go with
+    /// nothing to show for it (serde-rs/serde#1973). So the dispatch is
+    /// derived as an inherent `Event::deserialize` instead, and this looks at
+    /// `event` first: one in [`Event::variants`] goes on to the derived half
+    /// with the rest of the object, and any other is taken as
+    /// [`Event::Unknown`]. A known event that does not fit is still an error,
+    /// and says why.
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        de.deserialize_map(EventVisitor)
+    }
+}
+
+struct EventVisitor;
+
+impl<'de> Visitor<'de> for EventVisitor {
+    type Value = Event;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a journal event")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(
+        self,
+        mut map: A,
+    ) -> Result<Event, A::Error> {
+        // The game writes `event` right after `timestamp`, which an entry has
+        // already taken, so there is seldom anything to hold on to here.
+        let mut before = Vec::<(String, serde_json::Value)>::new();
+        let event = loop {
+            match map.next_key::<String>()? {
+                Some(key) if key == "event" => {
+                    break map.next_value::<String>()?
+                }
+                Some(key) => before.push((key, map.next_value()?)),
+                None => return Err(de::Error::missing_field("event")),
+            }
+        };
+
+        if !Event::variants().contains(&event.as_str()) {
+            let mut fields =
+                before.into_iter().collect::<serde_json::Map<_, _>>();
+            while let Some((key, value)) = map.next_entry()? {
+                fields.insert(key, value);
+            }
+            return Ok(Event::Unknown {
+                event,
+                fields: serde_json::Value::Object(fields),
+            });
+        }
+
+        Event::deserialize(MapAccessDeserializer::new(Tagged {
+            event: Some(event),
+            before: before.into_iter(),
+            value: None,
+            rest: map,
+        }))
+    }
+}
+
+/// An object with its `event` taken out to look at, put back in front
+struct Tagged<A> {
+    event: Option<String>,
+    /// Whatever came before `event`, with the value of the key last given
+    before: std::vec::IntoIter<(String, serde_json::Value)>,
+    value: Option<serde_json::Value>,
+    rest: A,
+}
+
+impl<'de, A: MapAccess<'de>> MapAccess<'de> for Tagged<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        if self.event.is_some() {
+            return seed.deserialize("event".into_deserializer()).map(Some);
+        }
+        match self.before.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(key.into_deserializer()).map(Some)
+            }
+            None => self.rest.next_key_seed(seed),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, A::Error> {
+        if let Some(event) = self.event.take() {
+            return seed.deserialize(event.into_deserializer());
+        }
+        match self.value.take() {
+            Some(value) => seed.deserialize(value).map_err(de::Error::custom),
+            None => self.rest.next_value_seed(seed),
+        }
+    }
+}
+
+impl Serialize for Event {
+    /// Write the derived dispatch back out, and [`Event::Unknown`] as it came
+    ///
+    /// The derived half cannot write an unknown event, having skipped it, so
+    /// it is written here: its `event` first and the rest of its fields after.
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let (event, fields) = match self {
+            Event::Unknown { event, fields } => (event, fields),
+            known => return Event::serialize(known, s),
+        };
+        let fields = fields.as_object();
+        let mut map = s.serialize_map(fields.map(|f| f.len() + 1))?;
+        map.serialize_entry("event", event)?;
+        for (key, value) in fields.into_iter().flatten() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
 }
 
+pub mod colonisation;
+pub mod combat;
+pub mod engineering;
 pub mod exploration;
+pub mod fleet_carriers;
+pub mod missions;
+pub mod on_foot;
+pub mod powerplay;
+pub mod progression;
+pub mod squadrons;
 pub mod startup;
+pub mod state;
+pub mod station_services;
+pub mod statistics;
+pub mod trade;
 pub mod travel;
 
+/// Something known about the commander that each entry can move on, such as
+/// [`missions::MissionLog`]
+///
+/// Starting from nothing and taking in every entry from the first journal on
+/// leaves it as the game last had it.
+pub trait Fold: Default {
+    /// Take in one more entry, which was written after every one before it
+    fn apply(&mut self, entry: &Entry<Event>);
+
+    /// Take in every one of `entries`, from nothing
+    fn fold<'a, I>(entries: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Entry<Event>>,
+    {
+        let mut folded = Self::default();
+        for entry in entries {
+            folded.apply(entry);
+        }
+        folded
+    }
+}
+
 /// Every event read here, read from both of the shapes it arrives in
 ///
 /// Written against the schema and the journal manual rather than against the
 /// structs. A struct that disagrees with the game about a field name parses
 /// nothing, and says so nowhere: the event simply never matches and is filed
-/// as [`Event::Other`] forever. That is not a failure any amount of running
+/// as [`Event::Unknown`] forever. That is not a failure any amount of running
 /// the thing will show you, which is what these are for.
 ///
 /// <https://github.com/EDCD/EDDN/tree/master/schemas>
@@ -246,9 +526,12 @@ pub mod travel;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::body::BodyType;
     use crate::entry::incremental::exploration::ScanTarget;
+    use crate::entry::status::LegalState;
     use crate::entry::Entry;
-    use crate::system::Economy;
+    use crate::faction::State;
+    use crate::system::{Economy, Power};
 
     /// Read a whole message the way the consumer does, tag and all
     fn event(json: &str) -> Event {
@@ -257,12 +540,13 @@ mod tests {
             .event
     }
 
-    /// Anything landing on `Other` was not read, whatever else was true of it
+    /// Anything landing on `Unknown` was not read, whatever else was true of
+    /// it
     fn assert_read(json: &str) -> Event {
         let event = event(json);
         assert!(
-            !matches!(event, Event::Other),
-            "fell through to Other: {}",
+            !matches!(event, Event::Unknown { .. }),
+            "fell through to Unknown: {}",
             json,
         );
         event
@@ -577,23 +861,95 @@ mod tests {
         assert_eq!(settlement.market_id, Some(3510085376));
     }
 
-    /// An event nothing here reads is filed as `Other` rather than refused
+    /// An event nothing here reads is kept as `Unknown` rather than refused
     ///
     /// Which is right, and is also why a struct that quietly disagrees with
     /// the game is invisible: it lands in exactly the same place.
     #[test]
-    fn an_unread_event_is_other() {
-        assert!(matches!(
-            event(
-                r#"{
-                    "timestamp": "2026-08-08T12:00:00Z",
-                    "event": "ProspectedAsteroid",
-                    "Content": "$AsteroidMaterialContent_High;",
-                    "Remaining": 100.0
-                }"#
-            ),
-            Event::Other
-        ));
+    fn an_unread_event_is_unknown() {
+        let Event::Unknown { event, fields } = event(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Music",
+                "MusicTrack": "Exploration"
+            }"#,
+        ) else {
+            panic!("an event nothing reads should be unknown")
+        };
+
+        assert_eq!(event, "Music");
+        // Everything it said, which used to go nowhere.
+        assert_eq!(fields["MusicTrack"], "Exploration");
+        assert!(fields.get("event").is_none());
+        assert!(fields.get("timestamp").is_none());
+    }
+
+    /// A known event that does not fit is still an error, not unknown
+    ///
+    /// Catching the rest must not catch these too, or every change the game
+    /// makes to a field would be filed away as an event nobody has modeled.
+    #[test]
+    fn a_known_event_short_a_field_is_not_unknown() {
+        let err = serde_json::from_str::<Entry<Event>>(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Undocked",
+                "StationName": "Ray Gateway"
+            }"#,
+        )
+        .expect_err("an undocking without its market should not read");
+
+        assert!(
+            err.to_string().contains("MarketID"),
+            "said nothing about the missing field: {}",
+            err,
+        );
+    }
+
+    /// Every variant is read by the derived half, and nothing else is
+    ///
+    /// A name in the list that fell through to `Unknown` would be an event
+    /// nothing reads, however its struct was written.
+    #[test]
+    fn every_variant_is_dispatched() {
+        let variants = Event::variants();
+        assert!(variants.contains(&"Fileheader"));
+        assert!(variants.contains(&"ColonisationContribution"));
+        assert!(!variants.contains(&"Unknown"));
+
+        for name in variants {
+            let read = serde_json::from_value::<Event>(
+                serde_json::json!({ "event": name }),
+            );
+            assert!(
+                !matches!(read, Ok(Event::Unknown { .. })),
+                "listed and not read: {}",
+                name,
+            );
+        }
+    }
+
+    /// `event` is looked for wherever it is, and put back in front
+    #[test]
+    fn an_event_named_last_reads_the_same() {
+        let undocked = serde_json::from_str::<Event>(
+            r#"{
+                "StationName": "Ray Gateway",
+                "MarketID": 3223343616,
+                "event": "Undocked"
+            }"#,
+        )
+        .expect("an undocking should read");
+        assert!(matches!(undocked, Event::Undocked(_)));
+
+        let Event::Unknown { event, fields } = serde_json::from_str::<Event>(
+            r#"{ "MusicTrack": "Exploration", "event": "Music" }"#,
+        )
+        .expect("music should read") else {
+            panic!("an event nothing reads should be unknown")
+        };
+        assert_eq!(event, "Music");
+        assert_eq!(fields["MusicTrack"], "Exploration");
     }
 
     /// The docking events that never reach EDDN, read as journal lines
@@ -660,7 +1016,7 @@ mod tests {
     /// Undocking, which had a struct and no way to reach it
     ///
     /// `Undocked` was written and never added to this enum, so every one of
-    /// them fell through to `Other` no matter what the struct said.
+    /// them fell through to `Unknown` no matter what the struct said.
     #[test]
     fn undocking_is_read() {
         let Event::Undocked(undocked) = assert_read(
@@ -1544,4 +1900,2000 @@ mod tests {
         assert_eq!(commander.name, "Nixpulvis");
         assert_eq!(commander.fid, None);
     }
+
+    /// Write a message out as a journal line and read it back again
+    ///
+    /// The line is held to the message it was written from, and what it reads
+    /// back as to writing the same line again. Lines are compared rather than
+    /// events, as a value that stands for nothing, such as
+    /// [`Government::None`][crate::Government::None], is never equal to
+    /// itself. The line is handed back to be looked at.
+    fn round_trip(json: &str) -> serde_json::Value {
+        round_trip_as(json, json)
+    }
+
+    /// [`round_trip`] a message the game words otherwise than it is written
+    ///
+    /// A placeholder reads as nothing and is left out, and a spelling read by
+    /// alias is written the one way. `written` is the message as it is
+    /// written here.
+    fn round_trip_as(json: &str, written: &str) -> serde_json::Value {
+        let read = serde_json::from_str::<Entry<Event>>(json)
+            .expect("message should parse");
+        let line = serde_json::to_string(&read).expect("entry should write");
+        let again = serde_json::from_str::<Entry<Event>>(&line)
+            .unwrap_or_else(|e| panic!("{} should read back: {}", line, e));
+        let line_again =
+            serde_json::to_string(&again).expect("entry should write");
+        assert_eq!(line, line_again, "changed on the way through");
+        let line: serde_json::Value = serde_json::from_str(&line).unwrap();
+        let expected: serde_json::Value =
+            serde_json::from_str(written).unwrap();
+        assert_eq!(
+            floats(line.clone()),
+            floats(expected),
+            "not written as expected: {}",
+            line,
+        );
+        line
+    }
+
+    /// Every number in `value` as a float, as `2` and `2.0` are one number
+    fn floats(value: serde_json::Value) -> serde_json::Value {
+        use serde_json::Value;
+
+        match value {
+            Value::Number(n) => {
+                n.as_f64().map_or(Value::Number(n), Value::from)
+            }
+            Value::Array(values) => values.into_iter().map(floats).collect(),
+            Value::Object(fields) => fields
+                .into_iter()
+                .map(|(name, value)| (name, floats(value)))
+                .collect(),
+            value => value,
+        }
+    }
+
+    /// A government of nothing, which is never equal to itself, written back
+    ///
+    /// Held to the line written, as two events carrying one never compare
+    /// equal, however alike.
+    #[test]
+    fn a_government_of_none_is_written_back() {
+        let json = r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "Docked", "StationName": "Zhang Hub", "StationType": "Outpost", "StarSystem": "Col 285 Sector AG-O d6-122", "SystemAddress": 4207509891435, "MarketID": 3700000000, "StationGovernment": "$government_None;", "DistFromStarLS": 12.5 }"#;
+        let Event::Docked(docked) = assert_read(json) else {
+            panic!("not docked")
+        };
+        assert!(matches!(
+            docked.station.government,
+            Some(crate::Government::None)
+        ));
+        assert_ne!(assert_read(json), assert_read(json));
+        round_trip_as(json, &json.replace("$government_None;", "None"));
+
+        let json = r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "FSDJump", "StarSystem": "Col 285 Sector AG-O d6-122", "SystemAddress": 4207509891435, "StarPos": [-75.0, 45.0, 61.0], "Factions": [ { "Name": "Workers of Col 285", "FactionState": "None", "Government": "None", "Influence": 1.0, "Allegiance": "Independent", "Happiness": "$Faction_HappinessBand2;" } ], "JumpDist": 58.2, "FuelUsed": 4.1, "FuelLevel": 27.9 }"#;
+        round_trip_as(json, &json.replace(r#""FactionState": "None", "#, ""));
+    }
+
+    /// Every kind of event read here, written out and read back unchanged
+    #[test]
+    fn an_event_written_out_reads_back_the_same() {
+        let messages = [
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Fileheader",
+                "part": 1,
+                "language": "English/UK",
+                "gameversion": "4.0.0.1904",
+                "build": "r308767/r0 "
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:01Z",
+                "event": "Cargo",
+                "Vessel": "Ship",
+                "Count": 4,
+                "Inventory": [
+                    { "Name": "gold", "Count": 3, "Stolen": 0 },
+                    { "Name": "tea", "Count": 1, "Stolen": 1, "MissionID": 7 }
+                ]
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:02Z",
+                "event": "Materials",
+                "Raw": [{ "Name": "iron", "Count": 12 }],
+                "Manufactured": [],
+                "Encoded": [{ "Name": "shielddensityreports", "Count": 3 }]
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:03Z",
+                "event": "FSDJump",
+                "StarSystem": "Sol",
+                "StarPos": [0.0, 0.0, 0.0],
+                "SystemAddress": 10477373803,
+                "Population": 22780919531,
+                "SystemSecurity": "High",
+                "SystemGovernment": "Democracy",
+                "SystemAllegiance": "Federation",
+                "SystemEconomy": "Refinery",
+                "SystemFaction": { "Name": "Mother Gaia", "FactionState": "Boom" },
+                "Factions": [
+                    {
+                        "Name": "Mother Gaia",
+                        "FactionState": "Boom",
+                        "Government": "Democracy",
+                        "Influence": 0.5,
+                        "Allegiance": "Federation",
+                        "Happiness": "$Faction_HappinessBand2;",
+                        "ActiveStates": [{ "State": "Boom" }]
+                    }
+                ],
+                "Powers": ["Jerome Archer"],
+                "PowerplayState": "Stronghold",
+                "JumpDist": 8.5,
+                "FuelUsed": 0.25,
+                "FuelLevel": 31.75
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:04Z",
+                "event": "Docked",
+                "StarSystem": "Sol",
+                "SystemAddress": 10477373803,
+                "StationName": "Galileo",
+                "StationType": "Ocellus",
+                "MarketID": 128016640,
+                "DistFromStarLS": 503.5,
+                "StationFaction": { "Name": "Mother Gaia" },
+                "StationGovernment": "Democracy",
+                "StationServices": ["dock", "refuel"],
+                "StationEconomies": [
+                    { "Name": "Tourism", "Proportion": 1.0 }
+                ],
+                "LandingPads": { "Small": 8, "Medium": 12, "Large": 4 }
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:05Z",
+                "event": "DockingDenied",
+                "StationName": "Galileo",
+                "MarketID": 128016640,
+                "Reason": "DockingUnavailable"
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:06Z",
+                "event": "ApproachSettlement",
+                "Name": "Ehrlich City",
+                "MarketID": 3510085376,
+                "SystemAddress": 10477373803,
+                "BodyID": 12,
+                "BodyName": "Sol 4",
+                "Latitude": 12.5,
+                "Longitude": -47.25
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:07Z",
+                "event": "NavRoute",
+                "Route": [
+                    {
+                        "StarSystem": "i Bootis",
+                        "SystemAddress": 1281787693419,
+                        "StarPos": [-22.375, 34.84375, 4.0],
+                        "StarClass": "G"
+                    }
+                ]
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:08Z",
+                "event": "SAASignalsFound",
+                "SystemAddress": 10477373803,
+                "BodyName": "Sol 4",
+                "BodyID": 12,
+                "Signals": [{ "Type": "$SAA_SignalType_Geological;", "Count": 3 }]
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:09Z",
+                "event": "FSSSignalDiscovered",
+                "StarSystem": "Sol",
+                "SystemAddress": 10477373803,
+                "signals": [
+                    {
+                        "timestamp": "2026-08-08T12:00:09Z",
+                        "SignalName": "Abraham Lincoln",
+                        "IsStation": true
+                    },
+                    {
+                        "timestamp": "2026-08-08T12:00:10Z",
+                        "SignalName": "$USS_HighGradeEmissions;",
+                        "ThreatLevel": 0
+                    }
+                ]
+            }"#,
+            r#"{
+                "timestamp": "2026-08-08T12:00:11Z",
+                "event": "CodexEntry",
+                "System": "Sol",
+                "SystemAddress": 10477373803,
+                "EntryID": 1400056,
+                "Name": "$Codex_Ent_Standard_Water_Worlds_Name;",
+                "Region": "$Codex_RegionName_18;"
+            }"#,
+        ];
+
+        for message in messages {
+            let line = round_trip(message);
+            let sent: serde_json::Value =
+                serde_json::from_str(message).expect("message is JSON");
+            // The game's own tag and names, rather than this crate's.
+            assert_eq!(line["event"], sent["event"]);
+            assert_eq!(line["timestamp"], sent["timestamp"]);
+            assert!(line.get("horizons").is_none());
+        }
+    }
+
+    /// Said back as it was said, down to the fields nothing here knows
+    #[test]
+    fn an_unknown_event_is_written_back_whole() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "Music",
+            "MusicTrack": "Exploration",
+            "odyssey": true
+        }"#;
+        let line = round_trip(json);
+
+        let sent: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(line, sent);
+    }
+
+    /// The commander loaded is `Commander`, and the commander named is `Name`
+    #[test]
+    fn a_loaded_game_names_its_commander_as_the_game_does() {
+        let line = round_trip(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "LoadGame",
+                "FID": "F1234567",
+                "Commander": "Nixpulvis",
+                "Horizons": true,
+                "Ship": "Anaconda",
+                "ShipID": 4,
+                "ShipName": "Exploratory",
+                "ShipIdent": "NX-01",
+                "GameMode": "Open",
+                "Credits": 120000,
+                "Loan": 0
+            }"#,
+        );
+        assert_eq!(line["Commander"], "Nixpulvis");
+        assert_eq!(line["FID"], "F1234567");
+        assert!(line.get("Name").is_none());
+
+        let line = round_trip(
+            r#"{
+                "timestamp": "2017-01-01T00:00:00Z",
+                "event": "Commander",
+                "Name": "Nixpulvis"
+            }"#,
+        );
+        assert_eq!(line["Name"], "Nixpulvis");
+    }
+
+    /// A scan keeps what it did not read, and does not say what it did twice
+    #[test]
+    fn a_scan_writes_back_what_it_did_not_read() {
+        let line = round_trip(
+            r#"{
+                "timestamp": "2026-08-11T18:00:00Z",
+                "event": "Scan",
+                "ScanType": "Detailed",
+                "StarSystem": "Sol",
+                "StarPos": [0.0, 0.0, 0.0],
+                "SystemAddress": 10477373803,
+                "BodyName": "Mars",
+                "BodyID": 4,
+                "BodyType": "Planet",
+                "Parents": [{ "Star": 0 }],
+                "PlanetClass": "Rocky body",
+                "TidalLock": false,
+                "MassEM": 0.125,
+                "Radius": 3389500.0,
+                "SurfaceGravity": 3.75,
+                "SurfaceTemperature": 210.0,
+                "AtmosphereType": "CarbonDioxide",
+                "SurfacePressure": 600.0,
+                "Composition": { "Ice": 0.0, "Rock": 0.75, "Metal": 0.25 },
+                "Landable": true,
+                "Atmosphere": "thin carbon dioxide atmosphere",
+                "Volcanism": "",
+                "TerraformState": "Terraformable",
+                "Materials": [{ "Name": "iron", "Percent": 20.5 }],
+                "SemiMajorAxis": 227900000000.0,
+                "Eccentricity": 0.09375,
+                "OrbitalInclination": 1.75,
+                "Periapsis": 286.5,
+                "OrbitalPeriod": 59355000.0,
+                "AscendingNode": 49.5,
+                "MeanAnomaly": 19.25,
+                "RotationPeriod": 88642.0,
+                "AxialTilt": 0.4375,
+                "DistanceFromArrivalLS": 760.0,
+                "WasDiscovered": true,
+                "WasMapped": true,
+                "ReserveLevel": "PristineResources"
+            }"#,
+        );
+
+        assert_eq!(line["ReserveLevel"], "PristineResources");
+        assert_eq!(line["Volcanism"], "");
+        // Sent wider than the target holds it, and written back as sent.
+        assert_eq!(line["SemiMajorAxis"], 227900000000.0);
+
+        // Renamed before writing, which is what is written.
+        let mut entry = serde_json::from_value::<Entry<Event>>(line).unwrap();
+        let Event::Scan(scan) = &mut entry.event else {
+            panic!("not a scan")
+        };
+        let ScanTarget::Body(body) = &mut scan.target else {
+            panic!("not a body")
+        };
+        body.name = "Barsoom".into();
+        let line = serde_json::to_value(&entry).unwrap();
+        assert_eq!(line["BodyName"], "Barsoom");
+        assert_eq!(line["ReserveLevel"], "PristineResources");
+    }
+
+    /// A signal the game wrote goes back on the event, as the game wrote it
+    #[test]
+    fn a_lone_signal_is_written_back_alone() {
+        let line = round_trip(
+            r#"{
+                "timestamp": "2022-03-17T18:20:53Z",
+                "event": "FSSSignalDiscovered",
+                "SystemAddress": 36293555558035,
+                "SignalName": "$USS_HighGradeEmissions;",
+                "SignalType": "USS",
+                "USSType": "$USS_Type_ValuableSalvage;",
+                "ThreatLevel": 0
+            }"#,
+        );
+
+        assert_eq!(line["SignalName"], "$USS_HighGradeEmissions;");
+        assert!(line.get("signals").is_none());
+        assert!(line.get("StarSystem").is_none());
+    }
+
+    /// Docked and browsing, which the game notes and then writes out in full
+    #[test]
+    fn a_market_opened_points_at_its_file() {
+        let Event::Market(market) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Market",
+                "MarketID": 3228885760,
+                "StationName": "Ray Gateway",
+                "StationType": "Coriolis",
+                "StarSystem": "Diaguandri"
+            }"#,
+        ) else {
+            panic!("not a market")
+        };
+        assert_eq!(market.market_id, 3228885760);
+        assert!(market.items.is_empty());
+
+        let line = round_trip(
+            r#"{
+                "timestamp": "2026-08-08T12:00:01Z",
+                "event": "Shipyard",
+                "MarketID": 3228885760,
+                "StationName": "Ray Gateway",
+                "StarSystem": "Diaguandri",
+                "Horizons": true,
+                "AllowCobraMkIV": false
+            }"#,
+        );
+        assert_eq!(line["AllowCobraMkIV"], false);
+        assert!(line.get("PriceList").is_none());
+
+        assert!(matches!(
+            assert_read(
+                r#"{
+                    "timestamp": "2026-08-08T12:00:02Z",
+                    "event": "Outfitting",
+                    "MarketID": 3228885760,
+                    "StationName": "Ray Gateway",
+                    "StarSystem": "Diaguandri"
+                }"#,
+            ),
+            Event::Outfitting(_)
+        ));
+    }
+
+    /// A ship with one engineered weapon, and a mode given in words
+    #[test]
+    fn a_loadout_with_its_engineering() {
+        let json = r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Loadout",
+                "Ship": "cobramkiii",
+                "ShipID": 1,
+                "ShipName": "Rocinante",
+                "ShipIdent": "UN-01",
+                "HullValue": 186260,
+                "ModulesValue": 1287331,
+                "HullHealth": 1.0,
+                "UnladenMass": 210.8,
+                "CargoCapacity": 16,
+                "MaxJumpRange": 21.09,
+                "FuelCapacity": { "Main": 16.0, "Reserve": 0.49 },
+                "Rebuy": 73680,
+                "Modules": [
+                    {
+                        "Slot": "MediumHardpoint1",
+                        "Item": "hpt_pulselaser_gimbal_medium",
+                        "On": true,
+                        "Priority": 0,
+                        "AmmoInClip": 0,
+                        "AmmoInHopper": 0,
+                        "Health": 1.0,
+                        "Value": 35399,
+                        "Engineering": {
+                            "Engineer": "The Dweller",
+                            "EngineerID": 300180,
+                            "BlueprintID": 128673532,
+                            "BlueprintName": "Weapon_Overcharged",
+                            "Level": 3,
+                            "Quality": 0.5,
+                            "ExperimentalEffect": "special_auto_loader",
+                            "ExperimentalEffect_Localised": "Auto Loader",
+                            "Modifiers": [
+                                {
+                                    "Label": "DamagePerSecond",
+                                    "Value": 9.8,
+                                    "OriginalValue": 7.8,
+                                    "LessIsGood": 0
+                                },
+                                {
+                                    "Label": "WeaponMode",
+                                    "ValueStr": "$WeaponMode_Pulse;",
+                                    "ValueStr_Localised": "Pulse"
+                                }
+                            ]
+                        }
+                    },
+                    {
+                        "Slot": "PowerPlant",
+                        "Item": "int_powerplant_size4_class2",
+                        "On": true,
+                        "Priority": 1,
+                        "Health": 0.93
+                    }
+                ]
+            }"#;
+        let Event::Loadout(loadout) = assert_read(json) else {
+            panic!("not a loadout")
+        };
+
+        assert_eq!(loadout.ship.name, "Rocinante");
+        assert_eq!(loadout.fuel_capacity.as_ref().map(|f| f.main), Some(16.0));
+        assert!(!loadout.hot);
+
+        let engineering = loadout.modules[0]
+            .engineering
+            .as_ref()
+            .expect("the laser was engineered");
+        assert_eq!(engineering.level, 3);
+        assert_eq!(engineering.modifiers[0].original_value, Some(7.8));
+        assert_eq!(engineering.modifiers[1].value, None);
+        assert_eq!(
+            engineering.modifiers[1].value_str_localised.as_deref(),
+            Some("Pulse")
+        );
+
+        assert_eq!(loadout.modules[1].value, None);
+        assert!(loadout.modules[1].engineering.is_none());
+        round_trip(json);
+    }
+
+    /// The on-foot lists, each under its own event and read the same way
+    #[test]
+    fn lockers_and_the_bar_are_read() {
+        let Event::ShipLocker(locker) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "ShipLocker",
+                "Items": [
+                    { "Name": "chemicalsample", "OwnerID": 0, "Count": 2 }
+                ],
+                "Components": [],
+                "Consumables": [],
+                "Data": []
+            }"#,
+        ) else {
+            panic!("not the ship locker")
+        };
+        assert_eq!(locker.items[0].count, 2);
+
+        assert!(matches!(
+            assert_read(
+                r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Backpack" }"#,
+            ),
+            Event::Backpack(_)
+        ));
+
+        let line = round_trip(
+            r#"{
+                "timestamp": "2026-08-08T12:00:02Z",
+                "event": "FCMaterials",
+                "MarketID": 3704402432,
+                "CarrierName": "Hand of Zeus",
+                "CarrierID": "K7Q-BQL"
+            }"#,
+        );
+        assert_eq!(line["event"], "FCMaterials");
+        assert_eq!(line["CarrierID"], "K7Q-BQL");
+    }
+
+    /// Hunting bounties, from the kill to the pay-out and the odd loss
+    #[test]
+    fn combat() {
+        let Event::Bounty(bounty) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Bounty",
+                "Rewards": [
+                    { "Faction": "Mother Gaia", "Reward": 17400 },
+                    { "Faction": "Sol Workers' Party", "Reward": 2250 }
+                ],
+                "Target": "cobramkiii",
+                "Target_Localised": "Cobra MkIII",
+                "TotalReward": 19650,
+                "VictimFaction": "Sol Pirates",
+                "PilotName": "$npc_name_decorate:#name=Jo Nash;",
+                "PilotName_Localised": "Jo Nash"
+            }"#,
+        ) else {
+            panic!("not a bounty")
+        };
+        assert_eq!(bounty.total(), 19650);
+        assert_eq!(bounty.rewards().len(), 2);
+
+        let Event::Bounty(skimmer) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:01Z",
+                "event": "Bounty",
+                "Target": "Skimmer",
+                "Target_Localised": "Skimmer",
+                "VictimFaction": "Sol Pirates",
+                "Faction": "Mother Gaia",
+                "Reward": 1000
+            }"#,
+        ) else {
+            panic!("not a bounty")
+        };
+        assert_eq!(skimmer.total(), 1000);
+        assert_eq!(skimmer.rewards()[0].faction, "Mother Gaia");
+
+        for bond in ["FactionKillBond", "CapShipBond"] {
+            let json = format!(
+                r#"{{
+                    "timestamp": "2026-08-08T12:00:02Z",
+                    "event": "{}",
+                    "Reward": 50000,
+                    "AwardingFaction": "Federation",
+                    "AwardingFaction_Localised": "Federation",
+                    "VictimFaction": "Empire"
+                }}"#,
+                bond
+            );
+            assert_read(&json);
+            round_trip(&json);
+        }
+
+        let Event::Died(died) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:03Z",
+                "event": "Died",
+                "Killers": [
+                    { "Name": "Cmdr Hunter", "Ship": "vulture", "Rank": "Deadly" },
+                    { "Name": "Cmdr Reaper", "Ship": "fer_de_lance", "Rank": "Elite" }
+                ]
+            }"#,
+        ) else {
+            panic!("not a death")
+        };
+        assert_eq!(died.killers()[1].name, "Cmdr Reaper");
+        assert_eq!(
+            died.killers()[1].rank,
+            Some(progression::CombatRank::Elite)
+        );
+
+        let Event::Died(died) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:04Z",
+                "event": "Died",
+                "KillerName": "$ShipName_Police_Federation;",
+                "KillerName_Localised": "Federal Security Service",
+                "KillerShip": "viper",
+                "KillerRank": "Deadly"
+            }"#,
+        ) else {
+            panic!("not a death")
+        };
+        assert_eq!(died.killers()[0].ship.as_deref(), Some("viper"));
+        assert_eq!(
+            died.killers()[0].rank,
+            Some(progression::CombatRank::Deadly)
+        );
+
+        let Event::Died(died) = assert_read(
+            r#"{ "timestamp": "2026-08-08T12:00:05Z", "event": "Died" }"#,
+        ) else {
+            panic!("not a death")
+        };
+        assert!(died.killers().is_empty());
+    }
+
+    /// Pulled out of supercruise, pulling others out, and getting away
+    #[test]
+    fn interdictions() {
+        let Event::Interdicted(interdicted) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Interdicted",
+                "Submitted": false,
+                "Interdictor": "Cmdr Hunter",
+                "IsPlayer": true,
+                "CombatRank": 7,
+                "Power": "Edmund Mahon"
+            }"#,
+        ) else {
+            panic!("not interdicted")
+        };
+        assert_eq!(
+            interdicted.combat_rank,
+            Some(progression::CombatRank::Deadly)
+        );
+        assert_eq!(interdicted.power, Some(Power::EdmundMahon));
+
+        let Event::Interdiction(interdiction) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:01Z",
+                "event": "Interdiction",
+                "Success": true,
+                "Interdicted": "$npc_name_decorate:#name=Jo Nash;",
+                "Interdicted_Localised": "Jo Nash",
+                "IsPlayer": false,
+                "Faction": "Sol Pirates"
+            }"#,
+        ) else {
+            panic!("not an interdiction")
+        };
+        assert_eq!(interdiction.combat_rank, None);
+
+        let Event::Interdiction(interdiction) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:01Z",
+                "event": "Interdiction",
+                "Success": true,
+                "Interdicted": "Cmdr Reaper",
+                "IsPlayer": true,
+                "CombatRank": 3
+            }"#,
+        ) else {
+            panic!("not an interdiction")
+        };
+        assert_eq!(
+            interdiction.combat_rank,
+            Some(progression::CombatRank::Competent)
+        );
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:02Z",
+            "event": "EscapeInterdiction",
+            "Interdictor": "$ShipName_PassengerLiner_Cruise;",
+            "Interdictor_Localised": "Cruise Ship",
+            "IsPlayer": false
+        }"#;
+        let Event::EscapeInterdiction(escaped) = assert_read(json) else {
+            panic!("not an escape")
+        };
+        assert!(!escaped.is_thargoid);
+        // Written back without the IsThargoid it was read without.
+        round_trip(json);
+
+        let Event::PvpKill(kill) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:03Z",
+                "event": "PVPKill",
+                "Victim": "Cmdr Hunter",
+                "CombatRank": 7
+            }"#,
+        ) else {
+            panic!("not a kill")
+        };
+        assert_eq!(kill.victim, "Cmdr Hunter");
+        assert_eq!(kill.combat_rank, progression::CombatRank::Deadly);
+    }
+
+    /// What happens to the ship in a fight
+    #[test]
+    fn taking_damage() {
+        assert!(matches!(
+            assert_read(
+                r#"{
+                    "timestamp": "2026-08-08T12:00:00Z",
+                    "event": "ShieldState",
+                    "ShieldsUp": false
+                }"#,
+            ),
+            Event::ShieldState(combat::ShieldState { shields_up: false })
+        ));
+
+        let Event::HullDamage(damage) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:01Z",
+                "event": "HullDamage",
+                "Health": 0.79,
+                "PlayerPilot": true,
+                "Fighter": false
+            }"#,
+        ) else {
+            panic!("not damage")
+        };
+        assert!(damage.player_pilot);
+
+        assert!(matches!(
+            assert_read(
+                r#"{
+                    "timestamp": "2026-08-08T12:00:02Z",
+                    "event": "UnderAttack",
+                    "Target": "Fighter"
+                }"#,
+            ),
+            Event::UnderAttack(combat::UnderAttack {
+                target: combat::AttackTarget::Fighter
+            })
+        ));
+
+        // Whatever else comes under attack is still read.
+        let Event::UnderAttack(attack) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:03Z",
+                "event": "UnderAttack",
+                "Target": "SRV"
+            }"#,
+        ) else {
+            panic!("not an attack")
+        };
+        assert_eq!(
+            attack.target,
+            combat::AttackTarget::Unknown("SRV".to_string())
+        );
+    }
+
+    /// A target scanned to the last stage, and then lost
+    #[test]
+    fn ship_targeted_in_stages() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "ShipTargeted",
+            "TargetLocked": true,
+            "Ship": "viper_mkiv",
+            "Ship_Localised": "Viper MkIV",
+            "ScanStage": 3,
+            "PilotName": "$npc_name_decorate:#name=Jo Nash;",
+            "PilotName_Localised": "Jo Nash",
+            "PilotRank": "Competent",
+            "ShieldHealth": 100.0,
+            "HullHealth": 87.5,
+            "Faction": "Sol Pirates",
+            "LegalStatus": "Wanted",
+            "Bounty": 12400,
+            "Subsystem": "$int_powerplant_size4_class2_name;",
+            "Subsystem_Localised": "Power Plant",
+            "SubsystemHealth": 100.0
+        }"#;
+        let Event::ShipTargeted(target) = assert_read(json) else {
+            panic!("not a target")
+        };
+        assert_eq!(target.scan_stage, Some(3));
+        assert_eq!(target.pilot_rank, Some(progression::CombatRank::Competent));
+        assert_eq!(target.legal_status, Some(LegalState::Wanted));
+        assert_eq!(target.bounty, Some(12400));
+        assert_eq!(target.hull_health, Some(87.5));
+        round_trip(json);
+
+        let Event::ShipTargeted(target) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:01Z",
+                "event": "ShipTargeted",
+                "TargetLocked": false
+            }"#,
+        ) else {
+            panic!("not a target")
+        };
+        assert_eq!(target.ship, None);
+    }
+
+    /// A run bought at one market and sold at another, and what it made
+    #[test]
+    fn trading() {
+        let Event::MarketBuy(bought) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "MarketBuy",
+                "MarketID": 128016384,
+                "Type": "gold",
+                "Count": 16,
+                "BuyPrice": 9150,
+                "TotalCost": 146400
+            }"#,
+        ) else {
+            panic!("not a purchase")
+        };
+        assert_eq!(bought.commodity, "gold");
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:30:00Z",
+            "event": "MarketSell",
+            "MarketID": 3228885760,
+            "Type": "gold",
+            "Count": 16,
+            "SellPrice": 10230,
+            "TotalSale": 163680,
+            "AvgPricePaid": 9150
+        }"#;
+        let Event::MarketSell(sold) = assert_read(json) else {
+            panic!("not a sale")
+        };
+        assert_eq!(sold.market_id, 3228885760);
+        assert_eq!(sold.profit(), 17280);
+        assert!(!sold.black_market);
+        round_trip(json);
+
+        let Event::MarketSell(fenced) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:40:00Z",
+                "event": "MarketSell",
+                "MarketID": 3228885760,
+                "Type": "battleweapons",
+                "Type_Localised": "Battle Weapons",
+                "Count": 2,
+                "SellPrice": 5200,
+                "TotalSale": 10400,
+                "AvgPricePaid": 0,
+                "IllegalGoods": true,
+                "StolenGoods": true,
+                "BlackMarket": true
+            }"#,
+        ) else {
+            panic!("not a sale")
+        };
+        assert!(fenced.illegal_goods && fenced.stolen_goods);
+        assert_eq!(fenced.profit(), 10400);
+
+        // A sum past the ends of an i64 stops there, rather than wrapping.
+        let wild = trade::MarketSell {
+            avg_price_paid: u64::MAX,
+            count: 2,
+            ..fenced
+        };
+        assert_eq!(wild.profit(), i64::MIN);
+        let wild = trade::MarketSell {
+            total_sale: u64::MAX,
+            avg_price_paid: 0,
+            ..wild
+        };
+        assert_eq!(wild.profit(), i64::MAX);
+
+        assert!(matches!(
+            assert_read(
+                r#"{
+                    "timestamp": "2026-08-08T12:50:00Z",
+                    "event": "BuyTradeData",
+                    "System": "Diaguandri",
+                    "Cost": 100
+                }"#,
+            ),
+            Event::BuyTradeData(_)
+        ));
+    }
+
+    /// Cargo that moves without a market: scooped, dumped, moved and depoted
+    #[test]
+    fn cargo_outside_a_market() {
+        let Event::CollectCargo(collected) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "CollectCargo",
+                "Type": "tea",
+                "Stolen": false,
+                "MissionID": 880456372
+            }"#,
+        ) else {
+            panic!("not collected")
+        };
+        assert_eq!(collected.mission_id, Some(880456372));
+
+        assert!(matches!(
+            assert_read(
+                r#"{
+                    "timestamp": "2026-08-08T12:00:01Z",
+                    "event": "EjectCargo",
+                    "Type": "tea",
+                    "Count": 2,
+                    "Abandoned": true
+                }"#,
+            ),
+            Event::EjectCargo(trade::EjectCargo {
+                abandoned: true,
+                ..
+            })
+        ));
+
+        let Event::CargoTransfer(transfer) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:02Z",
+                "event": "CargoTransfer",
+                "Transfers": [
+                    { "Type": "gold", "Count": 4, "Direction": "tocarrier" },
+                    { "Type": "tea", "Count": 1, "Direction": "tosrv" }
+                ]
+            }"#,
+        ) else {
+            panic!("not a transfer")
+        };
+        assert_eq!(
+            transfer.transfers[0].direction,
+            trade::TransferDirection::ToCarrier
+        );
+
+        let Event::CargoDepot(depot) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:03Z",
+                "event": "CargoDepot",
+                "MissionID": 880456372,
+                "UpdateType": "Collect",
+                "CargoType": "Gold",
+                "Count": 20,
+                "StartMarketID": 128016384,
+                "EndMarketID": 3228885760,
+                "ItemsCollected": 20,
+                "ItemsDelivered": 0,
+                "TotalItemsToDeliver": 60,
+                "Progress": 0.0
+            }"#,
+        ) else {
+            panic!("not a depot")
+        };
+        assert_eq!(depot.update_type, trade::CargoDepotUpdate::Collect);
+        assert_eq!(depot.end_market_id, 3228885760);
+    }
+
+    /// A core found, cracked and refined
+    #[test]
+    fn mining() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "ProspectedAsteroid",
+            "Materials": [
+                { "Name": "Bromellite", "Proportion": 32.5 },
+                { "Name": "LowTemperatureDiamond", "Name_Localised": "Low Temperature Diamonds", "Proportion": 11.2 }
+            ],
+            "MotherlodeMaterial": "Opal",
+            "MotherlodeMaterial_Localised": "Void Opal",
+            "Content": "$AsteroidMaterialContent_High;",
+            "Content_Localised": "Material Content: High",
+            "Remaining": 100.0
+        }"#;
+        let Event::ProspectedAsteroid(asteroid) = assert_read(json) else {
+            panic!("not prospected")
+        };
+        assert_eq!(asteroid.materials[1].proportion, 11.2);
+        assert_eq!(asteroid.motherlode_material.as_deref(), Some("Opal"));
+        round_trip(json);
+
+        assert!(matches!(
+            assert_read(
+                r#"{
+                    "timestamp": "2026-08-08T12:01:00Z",
+                    "event": "AsteroidCracked",
+                    "Body": "Paesia 2 A Ring"
+                }"#,
+            ),
+            Event::AsteroidCracked(_)
+        ));
+
+        let Event::MiningRefined(refined) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:02:00Z",
+                "event": "MiningRefined",
+                "Type": "$opal_name;",
+                "Type_Localised": "Void Opal"
+            }"#,
+        ) else {
+            panic!("not refined")
+        };
+        assert_eq!(refined.commodity_localised.as_deref(), Some("Void Opal"));
+    }
+
+    /// Fuel, repairs and supplies, each paid for at the station
+    #[test]
+    fn refuel_repair_and_restock() {
+        let messages = [
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "RefuelAll", "Cost": 353, "Amount": 7.06 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "RefuelPartial", "Cost": 50, "Amount": 1.0 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "Repair", "Item": "Wear", "Cost": 312 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:03Z", "event": "Repair", "Items": ["Hull", "$hpt_pulselaser_gimbal_medium_name;"], "Cost": 1202 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:04Z", "event": "RepairAll", "Cost": 1514 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:05Z", "event": "RestockVehicle", "Type": "independent_fighter", "Type_Localised": "Taipan", "Loadout": "four", "ID": 1, "Cost": 1030, "Count": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:06Z", "event": "BuyAmmo", "Cost": 80 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:07Z", "event": "BuyDrones", "Type": "Drones", "Count": 8, "BuyPrice": 101, "TotalCost": 808 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:08Z", "event": "SellDrones", "Type": "Drones", "Count": 2, "SellPrice": 101, "TotalSale": 202 }"#,
+        ];
+        for json in messages {
+            assert_read(json);
+            round_trip(json);
+        }
+
+        let Event::Repair(repair) = assert_read(messages[3]) else {
+            panic!("not a repair")
+        };
+        assert_eq!(repair.item, None);
+        assert_eq!(repair.items.len(), 2);
+    }
+
+    /// Ships bought, swapped and sent for, and what is stored
+    #[test]
+    fn the_shipyard() {
+        let messages = [
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "ShipyardBuy", "ShipType": "krait_mkii", "ShipType_Localised": "Krait Mk II", "ShipPrice": 42409425, "StoreOldShip": "CobraMkIII", "StoreShipID": 1, "MarketID": 128016384 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "ShipyardSell", "ShipType": "sidewinder", "SellShipID": 0, "ShipPrice": 25350, "System": "Eravate", "ShipMarketID": 3228342528, "MarketID": 128016384 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "ShipyardSwap", "ShipType": "cobramkiii", "ShipType_Localised": "Cobra MkIII", "ShipID": 1, "StoreOldShip": "Krait_MkII", "StoreShipID": 2, "MarketID": 128016384 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:03Z", "event": "ShipyardTransfer", "ShipType": "asp", "ShipType_Localised": "Asp Explorer", "ShipID": 3, "System": "Diaguandri", "ShipMarketID": 3228885760, "Distance": 118.4, "TransferPrice": 79134, "TransferTime": 2721, "MarketID": 128016384 }"#,
+        ];
+        for json in messages {
+            assert_read(json);
+            round_trip(json);
+        }
+
+        let Event::StoredShips(stored) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:04Z",
+                "event": "StoredShips",
+                "StationName": "Abraham Lincoln",
+                "MarketID": 128016384,
+                "StarSystem": "Sol",
+                "ShipsHere": [
+                    { "ShipID": 1, "ShipType": "cobramkiii", "Name": "Rocinante", "Value": 349718, "Hot": false }
+                ],
+                "ShipsRemote": [
+                    { "ShipID": 3, "ShipType": "asp", "ShipType_Localised": "Asp Explorer", "StarSystem": "Diaguandri", "ShipMarketID": 3228885760, "TransferPrice": 79134, "TransferTime": 2721, "Value": 6661153, "Hot": false },
+                    { "ShipID": 4, "ShipType": "type9", "InTransit": true, "Value": 76555842, "Hot": false }
+                ]
+            }"#,
+        ) else {
+            panic!("not stored ships")
+        };
+        assert_eq!(stored.ships_here[0].name.as_deref(), Some("Rocinante"));
+        assert_eq!(stored.ships_remote[0].ship_market_id, Some(3228885760));
+        assert!(stored.ships_remote[1].in_transit);
+    }
+
+    /// Modules bought, moved about and stored, engineered or not
+    #[test]
+    fn modules_bought_and_stored() {
+        let messages = [
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "ModuleBuy", "Slot": "Slot04_Size3", "BuyItem": "$int_shieldgenerator_size3_class5_name;", "BuyItem_Localised": "Shield Generator", "MarketID": 128016384, "BuyPrice": 507912, "Ship": "cobramkiii", "ShipID": 1, "SellItem": "$int_shieldgenerator_size3_class2_name;", "SellPrice": 6603 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "ModuleSell", "MarketID": 128016384, "Slot": "Slot06_Size2", "SellItem": "$int_cargorack_size2_class1_name;", "SellItem_Localised": "Cargo Rack", "SellPrice": 3250, "Ship": "cobramkiii", "ShipID": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "ModuleRetrieve", "MarketID": 128016384, "Slot": "MediumHardpoint1", "RetrievedItem": "$hpt_pulselaser_gimbal_medium_name;", "Ship": "cobramkiii", "ShipID": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:03Z", "event": "ModuleSwap", "MarketID": 128016384, "FromSlot": "Slot03_Size4", "ToSlot": "Slot05_Size2", "FromItem": "int_cargorack_size2_class1", "ToItem": "Null", "Ship": "cobramkiii", "ShipID": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:04Z", "event": "FetchRemoteModule", "StorageSlot": 12, "StoredItem": "$int_hyperdrive_size5_class5_name;", "ServerId": 128064133, "TransferCost": 18000, "TransferTime": 1500, "Ship": "asp", "ShipID": 3 }"#,
+        ];
+        for json in messages {
+            assert_read(json);
+            round_trip(json);
+        }
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:05Z",
+            "event": "ModuleStore",
+            "MarketID": 128016384,
+            "Slot": "MediumHardpoint1",
+            "StoredItem": "$hpt_pulselaser_gimbal_medium_name;",
+            "StoredItem_Localised": "Pulse Laser",
+            "Ship": "cobramkiii",
+            "ShipID": 1,
+            "EngineerModifications": "Weapon_Overcharged",
+            "Level": 3,
+            "Quality": 0.5
+        }"#;
+        let Event::ModuleStore(stored) = assert_read(json) else {
+            panic!("not stored")
+        };
+        let blueprint = stored.blueprint.expect("the laser was engineered");
+        assert_eq!(blueprint.name, "Weapon_Overcharged");
+        assert_eq!(blueprint.level, 3);
+        round_trip(json);
+
+        let Event::MassModuleStore(mass) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:06Z",
+                "event": "MassModuleStore",
+                "MarketID": 128016384,
+                "Ship": "cobramkiii",
+                "ShipID": 1,
+                "Items": [
+                    { "Slot": "TinyHardpoint1", "Name": "$hpt_shieldbooster_size0_class5_name;", "Hot": false, "EngineerModifications": "ShieldBooster_Resistive", "Level": 5, "Quality": 1.0 },
+                    { "Slot": "TinyHardpoint2", "Name": "$hpt_heatsinklauncher_turret_tiny_name;", "Hot": false }
+                ]
+            }"#,
+        ) else {
+            panic!("not a mass store")
+        };
+        assert!(mass.items[0].blueprint.is_some());
+        assert!(mass.items[1].blueprint.is_none());
+
+        let Event::StoredModules(stored) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:07Z",
+                "event": "StoredModules",
+                "MarketID": 128016384,
+                "StationName": "Abraham Lincoln",
+                "StarSystem": "Sol",
+                "Items": [
+                    { "Name": "$int_hyperdrive_size5_class5_name;", "Name_Localised": "FSD", "StorageSlot": 12, "StarSystem": "Diaguandri", "MarketID": 3228885760, "TransferCost": 18000, "TransferTime": 1500, "BuyPrice": 5103953, "Hot": false },
+                    { "Name": "$int_cargorack_size2_class1_name;", "StorageSlot": 13, "InTransit": true, "BuyPrice": 3250, "Hot": false }
+                ]
+            }"#,
+        ) else {
+            panic!("not stored modules")
+        };
+        assert_eq!(stored.items[0].market_id, Some(3228885760));
+        assert!(stored.items[1].in_transit);
+    }
+
+    /// Paying what is owed, and being paid what is owed to the commander
+    #[test]
+    fn fines_and_vouchers() {
+        let Event::PayFines(fines) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "PayFines",
+                "Amount": 4800,
+                "AllFines": true,
+                "ShipID": 1,
+                "BrokerPercentage": 25.0
+            }"#,
+        ) else {
+            panic!("not fines")
+        };
+        assert_eq!(fines.broker_percentage, Some(25.0));
+
+        assert!(matches!(
+            assert_read(
+                r#"{
+                    "timestamp": "2026-08-08T12:00:01Z",
+                    "event": "PayBounties",
+                    "Amount": 1200,
+                    "Faction": "$faction_Federation;",
+                    "Faction_Localised": "Federation",
+                    "ShipID": 1
+                }"#,
+            ),
+            Event::PayBounties(_)
+        ));
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:02Z",
+            "event": "RedeemVoucher",
+            "Type": "bounty",
+            "Amount": 19650,
+            "Factions": [
+                { "Faction": "Mother Gaia", "Amount": 17400 },
+                { "Faction": "", "Amount": 2250 }
+            ]
+        }"#;
+        let Event::RedeemVoucher(voucher) = assert_read(json) else {
+            panic!("not a voucher")
+        };
+        assert_eq!(voucher.voucher, station_services::Voucher::Bounty);
+        assert_eq!(voucher.factions[1].faction, "");
+        round_trip(json);
+
+        let Event::RedeemVoucher(voucher) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:03Z",
+                "event": "RedeemVoucher",
+                "Type": "CombatBond",
+                "Amount": 50000,
+                "Faction": "Federation"
+            }"#,
+        ) else {
+            panic!("not a voucher")
+        };
+        assert_eq!(voucher.voucher, station_services::Voucher::CombatBond);
+    }
+
+    /// A carrier's owner, looking it over and running it
+    #[test]
+    fn fleet_carriers() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "CarrierStats",
+            "CarrierID": 3704402432,
+            "Callsign": "K7Q-BQL",
+            "Name": "HAND OF ZEUS",
+            "DockingAccess": "squadronfriends",
+            "AllowNotorious": false,
+            "FuelLevel": 712,
+            "JumpRangeCurr": 500.0,
+            "JumpRangeMax": 500.0,
+            "PendingDecommission": false,
+            "SpaceUsage": {
+                "TotalCapacity": 25000,
+                "Crew": 1720,
+                "Cargo": 1408,
+                "CargoSpaceReserved": 200,
+                "ShipPacks": 0,
+                "ModulePacks": 1000,
+                "FreeSpace": 20672
+            },
+            "Finance": {
+                "CarrierBalance": 4861374120,
+                "ReserveBalance": 96580000,
+                "AvailableBalance": 4676718032,
+                "ReservePercent": 2,
+                "TaxRate_refuel": 10,
+                "TaxRate_repair": 10,
+                "TaxRate_rearm": 10
+            },
+            "Crew": [
+                { "CrewRole": "BlackMarket", "Activated": false },
+                { "CrewRole": "Refuel", "Activated": true, "Enabled": true, "CrewName": "Donna Moon" }
+            ],
+            "ShipPacks": [],
+            "ModulePacks": [
+                { "PackTheme": "Limpet Modules", "PackTier": 1 }
+            ]
+        }"#;
+        let Event::CarrierStats(stats) = assert_read(json) else {
+            panic!("not stats")
+        };
+        assert_eq!(
+            stats.docking_access,
+            fleet_carriers::DockingAccess::SquadronFriends
+        );
+        assert_eq!(stats.space_usage.free_space, 20672);
+        assert_eq!(stats.finance.tax_rate_refuel, Some(10.0));
+        assert_eq!(stats.finance.tax_rate_shipyard, None);
+        assert_eq!(stats.crew[1].crew_name.as_deref(), Some("Donna Moon"));
+        round_trip(json);
+
+        let messages = [
+            r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "CarrierJumpRequest", "CarrierID": 3704402432, "SystemName": "Paesia", "Body": "Paesia 2", "SystemAddress": 2832564133650, "BodyID": 8, "DepartureTime": "2026-08-08T12:15:10Z" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "CarrierJumpCancelled", "CarrierID": 3704402432 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:03Z", "event": "CarrierBuy", "CarrierID": 3704402432, "BoughtAtMarket": 3221524992, "Location": "Sol", "SystemAddress": 10477373803, "Price": 4999999999, "Variant": "CarrierDockB", "Callsign": "K7Q-BQL" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:04Z", "event": "CarrierDecommission", "CarrierID": 3704402432, "ScrapRefund": 4850000000, "ScrapTime": 1786800000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:05Z", "event": "CarrierBankTransfer", "CarrierID": 3704402432, "Deposit": 10000000, "PlayerBalance": 120000, "CarrierBalance": 4871374120 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:06Z", "event": "CarrierDepositFuel", "CarrierID": 3704402432, "Amount": 56, "Total": 768 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:07Z", "event": "CarrierCrewServices", "CarrierID": 3704402432, "CrewRole": "Exploration", "Operation": "Activate", "CrewName": "Kennedy Luna" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:08Z", "event": "CarrierFinance", "CarrierID": 3704402432, "TaxRate": 25, "CarrierBalance": 4871374120, "ReserveBalance": 96580000, "AvailableBalance": 4686718032, "ReservePercent": 2 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:09Z", "event": "CarrierTradeOrder", "CarrierID": 3704402432, "BlackMarket": false, "Commodity": "tritium", "PurchaseOrder": 1000, "Price": 51000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:10Z", "event": "CarrierDockingPermission", "CarrierID": 3704402432, "DockingAccess": "all", "AllowNotorious": true }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:11Z", "event": "CarrierNameChange", "CarrierID": 3704402432, "Callsign": "K7Q-BQL", "Name": "FIST OF ZEUS" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:12Z", "event": "CarrierModulePack", "CarrierID": 3704402432, "Operation": "BuyPack", "PackTheme": "Limpet Modules", "PackTier": 1, "Cost": 5000000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:13Z", "event": "CarrierShipPack", "CarrierID": 3704402432, "Operation": "SellPack", "PackTheme": "Zorgon Peterson - Commercial", "PackTier": 1, "Refund": 4000000 }"#,
+        ];
+        for json in messages {
+            assert_read(json);
+            round_trip(json);
+        }
+
+        let Event::CarrierJumpRequest(jump) = assert_read(messages[0]) else {
+            panic!("not a jump request")
+        };
+        assert_eq!(jump.carrier_id, 3704402432);
+        assert!(jump.departure_time.is_some());
+    }
+
+    /// A commander pledged, earning merits and moving up, then defecting
+    #[test]
+    fn powerplay() {
+        let Event::Powerplay(pledge) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Powerplay",
+                "Power": "A. Lavigny-Duval",
+                "Rank": 27,
+                "Merits": 48210,
+                "TimePledged": 8208000
+            }"#,
+        ) else {
+            panic!("not a pledge")
+        };
+        assert_eq!(pledge.power, Power::ArissaLavignyDuval);
+        assert_eq!(pledge.votes, None);
+
+        let messages = [
+            r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "PowerplayJoin", "Power": "Nakato Kaine" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "PowerplayLeave", "Power": "Nakato Kaine" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:03Z", "event": "PowerplayDefect", "FromPower": "Nakato Kaine", "ToPower": "Jerome Archer" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:04Z", "event": "PowerplayMerits", "Power": "Jerome Archer", "MeritsGained": 120, "TotalMerits": 48330 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:05Z", "event": "PowerplayRank", "Power": "Jerome Archer", "Rank": 28 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:06Z", "event": "PowerplayCollect", "Power": "Jerome Archer", "Type": "$powerspyware_name;", "Type_Localised": "Power Injection Malware", "Count": 12 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:07Z", "event": "PowerplayDeliver", "Power": "Jerome Archer", "Type": "$powerspyware_name;", "Type_Localised": "Power Injection Malware", "Count": 12 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:08Z", "event": "PowerplaySalary", "Power": "Jerome Archer", "Amount": 5000000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:09Z", "event": "PowerplayVoucher", "Power": "Jerome Archer", "Systems": ["Sol", "Alpha Centauri"] }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:10Z", "event": "PowerplayFastTrack", "Power": "Jerome Archer", "Cost": 50000 }"#,
+        ];
+        for json in messages {
+            assert_read(json);
+            round_trip(json);
+        }
+
+        let Event::PowerplayDefect(defect) = assert_read(messages[2]) else {
+            panic!("not a defection")
+        };
+        assert_eq!(defect.to_power, Power::JeromeArcher);
+    }
+
+    /// A member's whole career in a squadron, as a roster would follow it
+    #[test]
+    fn squadrons() {
+        let Event::SquadronStartup(startup) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "SquadronStartup",
+                "SquadronName": "INDEPENDENT FRONTIER LEGION",
+                "CurrentRank": 2
+            }"#,
+        ) else {
+            panic!("not a squadron")
+        };
+        assert_eq!(startup.current_rank, squadrons::SquadronRank::Officer);
+
+        for event in [
+            "SquadronCreated",
+            "AppliedToSquadron",
+            "InvitedToSquadron",
+            "JoinedSquadron",
+            "LeftSquadron",
+            "KickedFromSquadron",
+            "DisbandedSquadron",
+            "SharedBookmarkToSquadron",
+        ] {
+            let json = format!(
+                r#"{{
+                    "timestamp": "2026-08-08T12:00:01Z",
+                    "event": "{}",
+                    "SquadronName": "INDEPENDENT FRONTIER LEGION"
+                }}"#,
+                event
+            );
+            assert_read(&json);
+            round_trip(&json);
+        }
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:02Z",
+            "event": "SquadronPromotion",
+            "SquadronName": "INDEPENDENT FRONTIER LEGION",
+            "OldRank": 3,
+            "NewRank": 4
+        }"#;
+        let Event::SquadronPromotion(promotion) = assert_read(json) else {
+            panic!("not a promotion")
+        };
+        assert_eq!(promotion.old_rank, squadrons::SquadronRank::SeniorOfficer);
+        assert_eq!(promotion.new_rank, squadrons::SquadronRank::Leader);
+        assert_eq!(round_trip(json)["NewRank"], 4);
+        assert!(promotion.new_rank > promotion.old_rank);
+        assert_eq!(promotion.old_rank.to_string(), "Senior Officer");
+        assert_eq!(
+            "Senior Officer".parse::<squadrons::SquadronRank>(),
+            Ok(promotion.old_rank)
+        );
+
+        let Event::SquadronDemotion(demotion) = assert_read(
+            r#"{
+                "timestamp": "2026-08-08T12:00:03Z",
+                "event": "SquadronDemotion",
+                "SquadronName": "INDEPENDENT FRONTIER LEGION",
+                "OldRank": 9,
+                "NewRank": 0
+            }"#,
+        ) else {
+            panic!("not a demotion")
+        };
+        assert_eq!(demotion.old_rank, squadrons::SquadronRank::Other(9));
+    }
+
+    /// A mission from its offer to its reward, with the factions it moved
+    #[test]
+    fn missions() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "MissionAccepted",
+            "Faction": "Mother Gaia",
+            "Name": "Mission_Delivery_Boom",
+            "LocalisedName": "Boom time delivery of 20 units of Gold",
+            "Commodity": "$Gold_Name;",
+            "Commodity_Localised": "Gold",
+            "Count": 20,
+            "DestinationSystem": "Diaguandri",
+            "DestinationStation": "Ray Gateway",
+            "Expiry": "2026-08-09T12:00:00Z",
+            "Wing": false,
+            "Influence": "++",
+            "Reputation": "++",
+            "Reward": 1250000,
+            "MissionID": 880456372
+        }"#;
+        let Event::MissionAccepted(accepted) = assert_read(json) else {
+            panic!("not a mission accepted")
+        };
+        assert_eq!(accepted.state(), Some(State::Boom));
+        assert_eq!(round_trip(json)["Expiry"], "2026-08-09T12:00:00Z");
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:30:00Z",
+            "event": "MissionCompleted",
+            "Faction": "Mother Gaia",
+            "Name": "Mission_Delivery_Boom_name",
+            "MissionID": 880456372,
+            "Commodity": "$Gold_Name;",
+            "Commodity_Localised": "Gold",
+            "Count": 20,
+            "DestinationSystem": "Diaguandri",
+            "DestinationStation": "Ray Gateway",
+            "Reward": 1250000,
+            "MaterialsReward": [
+                {
+                    "Name": "Vanadium",
+                    "Category": "$MICRORESOURCE_CATEGORY_Raw;",
+                    "Category_Localised": "Raw",
+                    "Count": 3
+                }
+            ],
+            "FactionEffects": [
+                {
+                    "Faction": "Mother Gaia",
+                    "Effects": [
+                        {
+                            "Effect": "$MISSIONUTIL_Interaction_Summary_EP_up;",
+                            "Effect_Localised": "The economic status of $#MinorFaction; has improved in the $#System; system.",
+                            "Trend": "UpGood"
+                        }
+                    ],
+                    "Influence": [
+                        {
+                            "SystemAddress": 3932277478106,
+                            "Trend": "UpGood",
+                            "Influence": "++"
+                        }
+                    ],
+                    "ReputationTrend": "UpGood",
+                    "Reputation": "++"
+                },
+                {
+                    "Faction": "",
+                    "Effects": [],
+                    "Influence": [],
+                    "ReputationTrend": "",
+                    "Reputation": ""
+                }
+            ]
+        }"#;
+        let Event::MissionCompleted(completed) = assert_read(json) else {
+            panic!("not a mission completed")
+        };
+        assert_eq!(completed.materials_reward[0].count, 3);
+        let effects = &completed.faction_effects[0];
+        assert_eq!(effects.influence[0].trend, missions::Trend::UpGood);
+        assert_eq!(
+            completed.faction_effects[1].reputation_trend,
+            missions::Trend::None
+        );
+        round_trip_as(
+            json,
+            &json.replace(
+                r#""ReputationTrend": """#,
+                r#""ReputationTrend": "None""#,
+            ),
+        );
+
+        for json in [
+            r#"{ "timestamp": "2026-08-08T12:31:00Z", "event": "MissionFailed", "Name": "Mission_Courier_name", "MissionID": 880456400, "Fine": 5000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:32:00Z", "event": "MissionAbandoned", "Name": "Mission_Salvage_name", "MissionID": 880400000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:33:00Z", "event": "MissionRedirected", "MissionID": 880456401, "Name": "Mission_Assassinate", "NewDestinationStation": "Ray Gateway", "NewDestinationSystem": "Diaguandri", "OldDestinationStation": "", "OldDestinationSystem": "Sol" }"#,
+            r#"{ "timestamp": "2026-08-08T13:00:00Z", "event": "Missions", "Active": [ { "MissionID": 880456401, "Name": "Mission_Assassinate_name", "PassengerMission": false, "Expires": 82800 } ], "Failed": [], "Complete": [] }"#,
+        ] {
+            assert_read(json);
+            round_trip(json);
+        }
+    }
+
+    /// A commander off their ship: getting there, dressing for it, and what
+    /// they come back with
+    #[test]
+    fn on_foot() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "Disembark",
+            "odyssey": true,
+            "SRV": false,
+            "Taxi": false,
+            "Multicrew": false,
+            "ID": 36,
+            "StarSystem": "HIP 22460",
+            "SystemAddress": 594676084435,
+            "Body": "HIP 22460 A 3",
+            "BodyID": 12,
+            "OnStation": false,
+            "OnPlanet": true,
+            "StationName": "Shepard Ridge",
+            "StationType": "OnFootSettlement",
+            "MarketID": 3869926656
+        }"#;
+        let Event::Disembark(disembark) = assert_read(json) else {
+            panic!("not a disembark")
+        };
+        assert!(disembark.on_planet);
+        assert_eq!(round_trip(json)["odyssey"], true);
+
+        for json in [
+            r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Embark", "SRV": false, "Taxi": true, "Multicrew": false, "StarSystem": "HIP 22460", "SystemAddress": 594676084435, "Body": "Grabe Terminal", "BodyID": 40, "OnStation": true, "OnPlanet": false, "StationName": "Grabe Terminal", "StationType": "Coriolis", "MarketID": 3223343616 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "BookTaxi", "Cost": 5100, "DestinationSystem": "Ix", "DestinationLocation": "Lopez Gateway" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:03Z", "event": "BookDropship", "Retreat": true, "Cost": 0, "DestinationSystem": "HIP 22460", "DestinationLocation": "Grabe Terminal" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:04Z", "event": "CancelTaxi", "Refund": 5100 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:05Z", "event": "BuySuit", "Name": "UtilitySuit_Class1", "Name_Localised": "Maverick Suit", "Price": 150000, "SuitID": 1700217809818876 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:06Z", "event": "SellSuit", "SuitID": 1698502991022131, "SuitMods": [ "suit_reducedtoolbatteryconsumption" ], "Name": "explorationsuit_class1", "Name_Localised": "Artemis Suit", "Price": 90000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:07Z", "event": "UpgradeSuit", "Name": "utilitysuit_class1", "Name_Localised": "Maverick Suit", "SuitID": 1700217809818876, "Class": 2, "Cost": 500000, "Resources": [ { "Name": "ElectricalWiring", "Name_Localised": "Electrical Wiring", "Count": 5 } ] }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:08Z", "event": "CreateSuitLoadout", "SuitID": 1700217809818876, "SuitName": "utilitysuit_class2", "SuitName_Localised": "Maverick Suit", "SuitMods": [], "LoadoutID": 4293000005, "LoadoutName": "Salvage", "Modules": [ { "SlotName": "PrimaryWeapon1", "SuitModuleID": 1700217863661544, "ModuleName": "wpn_m_assaultrifle_kinetic_fauto", "ModuleName_Localised": "Karma AR-50", "Class": 1, "WeaponMods": [] } ] }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:09Z", "event": "SwitchSuitLoadout", "SuitID": 1700217809818876, "SuitName": "utilitysuit_class2", "SuitMods": [], "LoadoutID": 4293000005, "LoadoutName": "Salvage", "Modules": [] }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:10Z", "event": "BuyWeapon", "Name": "Wpn_M_AssaultRifle_Kinetic_FAuto", "Name_Localised": "Karma AR-50", "Price": 125000, "SuitModuleID": 1700217863661544, "Class": 1, "WeaponMods": [] }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:11Z", "event": "UpgradeWeapon", "Name": "wpn_m_assaultrifle_kinetic_fauto", "Name_Localised": "Karma AR-50", "SuitModuleID": 1700217863661544, "Class": 2, "Cost": 60000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:12Z", "event": "CollectItems", "Name": "powerregulator", "Name_Localised": "Power Regulator", "Type": "Item", "OwnerID": 0, "Count": 1, "Stolen": true }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:13Z", "event": "DropItems", "Name": "healthpack", "Name_Localised": "Medkit", "Type": "Consumable", "OwnerID": 0, "Count": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:14Z", "event": "TradeMicroResources", "Offered": [ { "Name": "graphene", "Category": "Component", "Count": 30 } ], "TotalCount": 30, "Received": "carbonfibreplating", "Received_Localised": "Carbon Fibre Plating", "Category": "Component", "Count": 6, "MarketID": 3221524992 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:15Z", "event": "TransferMicroResources", "Transfers": [ { "Name": "healthpack", "Name_Localised": "Medkit", "Category": "Consumable", "LockerOldCount": 10, "LockerNewCount": 8, "Direction": "ToBackpack" } ] }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:16Z", "event": "UseConsumable", "Name": "healthpack", "Name_Localised": "Medkit", "Type": "Consumable" }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:17Z", "event": "BackpackChange", "Removed": [ { "Name": "healthpack", "Name_Localised": "Medkit", "OwnerID": 0, "Count": 1, "Type": "Consumable" } ] }"#,
+        ] {
+            assert_read(json);
+            round_trip(json);
+        }
+    }
+
+    /// Bought in one kind at a time, once, and in lists since
+    #[test]
+    fn micro_resources_bought_either_way() {
+        let old = r#"{
+            "timestamp": "2021-05-20T12:00:00Z",
+            "event": "BuyMicroResources",
+            "Name": "healthpack",
+            "Name_Localised": "Medkit",
+            "Category": "Consumable",
+            "Count": 5,
+            "Price": 5000,
+            "MarketID": 3221524992
+        }"#;
+        let new = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "SellMicroResources",
+            "TotalCount": 3,
+            "MicroResources": [
+                { "Name": "insight", "Category": "Data", "Count": 2 },
+                { "Name": "ionbattery", "Category": "Goods", "Count": 1 }
+            ],
+            "Price": 4500,
+            "MarketID": 3221524992
+        }"#;
+
+        let Event::BuyMicroResources(bought) = assert_read(old) else {
+            panic!("not a purchase")
+        };
+        let bought = bought.micro_resources().collect::<Vec<_>>();
+        assert_eq!(bought.len(), 1);
+        assert_eq!(bought[0].category, on_foot::MicroResourceType::Consumable);
+
+        let Event::SellMicroResources(sold) = assert_read(new) else {
+            panic!("not a sale")
+        };
+        assert_eq!(sold.micro_resources().map(|m| m.count).sum::<u64>(), 3);
+        assert_eq!(
+            sold.micro_resources[1].category,
+            on_foot::MicroResourceType::Unknown("Goods".into())
+        );
+
+        round_trip(old);
+        round_trip(new);
+    }
+
+    /// A species sampled three times over, then sold
+    #[test]
+    fn exobiology() {
+        for scan_type in ["Log", "Sample", "Analyse"] {
+            let json = format!(
+                r#"{{
+                    "timestamp": "2026-08-08T12:00:00Z",
+                    "event": "ScanOrganic",
+                    "ScanType": "{}",
+                    "Genus": "$Codex_Ent_Bacterial_Genus_Name;",
+                    "Genus_Localised": "Bacterium",
+                    "Species": "$Codex_Ent_Bacterial_04_Name;",
+                    "Species_Localised": "Bacterium Acies",
+                    "Variant": "$Codex_Ent_Bacterial_04_Antimony_Name;",
+                    "Variant_Localised": "Bacterium Acies - Cobalt",
+                    "SystemAddress": 594676084435,
+                    "Body": 12
+                }}"#,
+                scan_type
+            );
+            let Event::ScanOrganic(scan) = assert_read(&json) else {
+                panic!("not an organic scan")
+            };
+            assert_eq!(scan.body_id, 12);
+            round_trip(&json);
+        }
+
+        let json = r#"{
+            "timestamp": "2026-08-08T13:00:00Z",
+            "event": "SellOrganicData",
+            "MarketID": 3223343616,
+            "BioData": [
+                {
+                    "Genus": "$Codex_Ent_Bacterial_Genus_Name;",
+                    "Genus_Localised": "Bacterium",
+                    "Species": "$Codex_Ent_Bacterial_04_Name;",
+                    "Species_Localised": "Bacterium Acies",
+                    "Value": 1000000,
+                    "Bonus": 4000000
+                }
+            ]
+        }"#;
+        let Event::SellOrganicData(sold) = assert_read(json) else {
+            panic!("not organic data sold")
+        };
+        assert_eq!(sold.total(), 5000000);
+        round_trip(json);
+    }
+
+    /// A module engineered, from meeting the engineer to the roll itself
+    #[test]
+    fn engineering() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "EngineerProgress",
+            "Engineers": [
+                { "Engineer": "Felicity Farseer", "EngineerID": 300100, "Progress": "Unlocked", "RankProgress": 0, "Rank": 5 },
+                { "Engineer": "Elvira Martuuk", "EngineerID": 300160, "Progress": "Invited" }
+            ]
+        }"#;
+        let Event::EngineerProgress(progress) = assert_read(json) else {
+            panic!("not engineer progress")
+        };
+        assert_eq!(progress.engineers().count(), 2);
+        round_trip(json);
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:01Z",
+            "event": "EngineerProgress",
+            "Engineer": "Elvira Martuuk",
+            "EngineerID": 300160,
+            "Progress": "Unlocked"
+        }"#;
+        let Event::EngineerProgress(progress) = assert_read(json) else {
+            panic!("not engineer progress")
+        };
+        let engineers = progress.engineers().collect::<Vec<_>>();
+        assert_eq!(engineers.len(), 1);
+        assert_eq!(
+            engineers[0].progress,
+            Some(engineering::EngineerStage::Unlocked)
+        );
+        round_trip(json);
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:05:00Z",
+            "event": "EngineerCraft",
+            "Slot": "MediumHardpoint2",
+            "Module": "hpt_pulselaser_gimbal_medium",
+            "Ingredients": [
+                { "Name": "iron", "Count": 1 },
+                { "Name": "hybridcapacitors", "Name_Localised": "Hybrid Capacitors", "Count": 1 }
+            ],
+            "Engineer": "Felicity Farseer",
+            "EngineerID": 300100,
+            "BlueprintID": 128673573,
+            "BlueprintName": "Weapon_Overcharged",
+            "Level": 2,
+            "Quality": 0.5,
+            "Modifiers": [
+                { "Label": "DamagePerSecond", "Value": 11.2, "OriginalValue": 9.8, "LessIsGood": 0 }
+            ]
+        }"#;
+        let Event::EngineerCraft(craft) = assert_read(json) else {
+            panic!("not a craft")
+        };
+        assert_eq!(craft.engineering.level, 2);
+        assert_eq!(craft.ingredients.len(), 2);
+        round_trip(json);
+
+        for json in [
+            r#"{ "timestamp": "2026-08-08T12:06:00Z", "event": "EngineerContribution", "Engineer": "Elvira Martuuk", "EngineerID": 300160, "Type": "Commodity", "Commodity": "soontillrelics", "Commodity_Localised": "Soontill Relics", "Quantity": 3, "TotalQuantity": 3 }"#,
+            r#"{ "timestamp": "2026-08-08T12:07:00Z", "event": "MaterialCollected", "Category": "Encoded", "Name": "shielddensityreports", "Name_Localised": "Untypical Shield Scans ", "Count": 3 }"#,
+            r#"{ "timestamp": "2026-08-08T12:08:00Z", "event": "MaterialDiscarded", "Category": "Raw", "Name": "iron", "Count": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:10:00Z", "event": "Synthesis", "Name": "FSD Basic", "Materials": [ { "Name": "carbon", "Count": 1 }, { "Name": "vanadium", "Count": 1 } ] }"#,
+            r#"{ "timestamp": "2026-08-08T12:11:00Z", "event": "TechnologyBroker", "BrokerType": "guardian", "MarketID": 3223529472, "ItemsUnlocked": [ { "Name": "Hpt_Guardian_GaussCannon_Fixed_Medium", "Name_Localised": "Guardian Gauss Cannon" } ], "Commodities": [ { "Name": "guardian_moduleblueprint", "Name_Localised": "Guardian Module Blueprint Segment", "Count": 4 } ], "Materials": [ { "Name": "guardian_powercell", "Name_Localised": "Guardian Power Cell", "Count": 18, "Category": "Manufactured" } ] }"#,
+            r#"{ "timestamp": "2026-08-08T12:12:00Z", "event": "ScientificResearch", "MarketID": 3228883456, "Name": "nickel", "Category": "Raw", "Count": 5 }"#,
+        ] {
+            assert_read(json);
+            round_trip(json);
+        }
+
+        // The trader's own kind is the only place the game writes it lower
+        // case.
+        let json = r#"{ "timestamp": "2026-08-08T12:09:00Z", "event": "MaterialTrade", "MarketID": 3221397760, "TraderType": "raw", "Paid": { "Material": "iron", "Category": "Raw", "Quantity": 6 }, "Received": { "Material": "nickel", "Category": "Raw", "Quantity": 1 } }"#;
+        round_trip_as(json, &json.replace(r#""raw""#, r#""Raw""#));
+    }
+
+    /// Where a commander stands, as written on loading a game and as it moves
+    #[test]
+    fn ranks_and_progress() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "Rank",
+            "Combat": 8,
+            "Trade": 11,
+            "Explore": 13,
+            "Soldier": 0,
+            "Exobiologist": 3,
+            "Empire": 14,
+            "Federation": 12,
+            "CQC": 1
+        }"#;
+        let Event::Rank(rank) = assert_read(json) else {
+            panic!("not a rank")
+        };
+        assert_eq!(rank.combat, progression::CombatRank::Elite);
+        assert_eq!(rank.trade.to_string(), "Elite III");
+        assert_eq!(rank.empire, progression::EmpireRank::King);
+        assert_eq!(rank.federation.to_string(), "Rear Admiral");
+        assert_eq!(rank.cqc.to_string(), "Mostly Helpless");
+        assert!(rank.explore > progression::ExploreRank::Elite);
+        assert_eq!(round_trip(json)["Explore"], 13);
+
+        // Before Odyssey there was nothing to rank on foot.
+        let Event::Rank(rank) = assert_read(
+            r#"{ "timestamp": "2020-08-08T12:00:00Z", "event": "Rank", "Combat": 3, "Trade": 2, "Explore": 4, "Empire": 0, "Federation": 0, "CQC": 0 }"#,
+        ) else {
+            panic!("not a rank")
+        };
+        assert_eq!(rank.soldier, None);
+        assert_eq!(rank.federation.to_string(), "None");
+        assert_eq!(
+            "Mostly Harmless".parse(),
+            Ok(progression::CombatRank::MostlyHarmless)
+        );
+        assert_eq!("Rank 14".parse(), Ok(progression::CombatRank::Other(14)));
+        assert!("Harmful".parse::<progression::CombatRank>().is_err());
+
+        let json = r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Promotion", "Combat": 14 }"#;
+        let Event::Promotion(promotion) = assert_read(json) else {
+            panic!("not a promotion")
+        };
+        assert_eq!(promotion.combat, Some(progression::CombatRank::Other(14)));
+        assert!(promotion.combat > Some(progression::CombatRank::EliteV));
+        assert_eq!(promotion.trade, None);
+        round_trip(json);
+
+        for json in [
+            r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "Progress", "Combat": 45, "Trade": 100, "Explore": 0, "Soldier": 12, "Exobiologist": 60, "Empire": 100, "Federation": 3, "CQC": 0 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:03Z", "event": "Reputation", "Empire": 100.0, "Federation": 91.5, "Alliance": -12.25 }"#,
+        ] {
+            assert_read(json);
+            round_trip(json);
+        }
+    }
+
+    /// The groups the game writes, and one from before some of them were
+    #[test]
+    fn statistics() {
+        let json = r#"{
+            "timestamp": "2026-08-08T12:00:00Z",
+            "event": "Statistics",
+            "Bank_Account": { "Current_Wealth": 8934823341, "Spent_On_Ships": 1210000000, "Spent_On_Outfitting": 1900000000, "Spent_On_Repairs": 4300000, "Spent_On_Fuel": 250000, "Spent_On_Ammo_Consumables": 900000, "Insurance_Claims": 7, "Spent_On_Insurance": 64000000, "Owned_Ship_Count": 12, "Spent_On_Suits": 0, "Spent_On_Weapons": 0, "Spent_On_Suit_Consumables": 0, "Suits_Owned": 1, "Weapons_Owned": 2, "Spent_On_Premium_Stock": 0, "Premium_Stock_Bought": 0 },
+            "Combat": { "Bounties_Claimed": 1200, "Bounty_Hunting_Profit": 420000000, "Combat_Bonds": 300, "Combat_Bond_Profits": 90000000, "Assassinations": 20, "Assassination_Profits": 30000000, "Highest_Single_Reward": 2500000, "Skimmers_Killed": 40, "OnFoot_Combat_Bonds": 0, "OnFoot_Combat_Bonds_Profits": 0, "OnFoot_Vehicles_Destroyed": 0, "OnFoot_Ships_Destroyed": 0, "Dropships_Taken": 0, "Dropships_Booked": 0, "Dropships_Cancelled": 0, "ConflictZone_High": 0, "ConflictZone_Medium": 0, "ConflictZone_Low": 0, "ConflictZone_Total": 0, "ConflictZone_High_Wins": 0, "ConflictZone_Medium_Wins": 0, "ConflictZone_Low_Wins": 0, "ConflictZone_Total_Wins": 0, "Settlement_Defended": 0, "Settlement_Conquered": 0, "OnFoot_Skimmers_Killed": 0, "OnFoot_Scavs_Killed": 0 },
+            "Crime": { "Notoriety": 0, "Fines": 31, "Total_Fines": 120000, "Bounties_Received": 5, "Total_Bounties": 8000, "Highest_Bounty": 4000, "Malware_Uploaded": 0, "Settlements_State_Shutdown": 0, "Production_Sabotage": 0, "Production_Theft": 0, "Total_Murders": 0, "Citizens_Murdered": 0, "Omnipol_Murdered": 0, "Guards_Murdered": 0, "Data_Stolen": 0, "Goods_Stolen": 0, "Sample_Stolen": 0, "Total_Stolen": 0, "Turrets_Destroyed": 0, "Turrets_Overloaded": 0, "Turrets_Total": 0, "Value_Stolen_StateChange": 0, "Profiles_Cloned": 0 },
+            "Smuggling": { "Black_Markets_Traded_With": 4, "Black_Markets_Profits": 2000000, "Resources_Smuggled": 300, "Average_Profit": 500000.5, "Highest_Single_Transaction": 900000 },
+            "Trading": { "Markets_Traded_With": 200, "Market_Profits": 3000000000, "Resources_Traded": 500000, "Average_Profit": 3000000.25, "Highest_Single_Transaction": 180000000, "Data_Sold": 0, "Goods_Sold": 0, "Assets_Sold": 0 },
+            "Mining": { "Mining_Profits": 800000000, "Quantity_Mined": 3000, "Materials_Collected": 9000 },
+            "Exploration": { "Systems_Visited": 9000, "Exploration_Profits": 900000000, "Planets_Scanned_To_Level_2": 3000, "Planets_Scanned_To_Level_3": 2500, "Efficient_Scans": 400, "Highest_Payout": 20000000, "Total_Hyperspace_Distance": 600000, "Total_Hyperspace_Jumps": 14000, "Greatest_Distance_From_Start": 65000.123, "Time_Played": 7200000, "OnFoot_Distance_Travelled": 0, "Shuttle_Journeys": 0, "Shuttle_Distance_Travelled": 0.0, "Spent_On_Shuttles": 0, "First_Footfalls": 0, "Planet_Footfalls": 0, "Settlements_Visited": 0 },
+            "Passengers": { "Passengers_Missions_Accepted": 60, "Passengers_Missions_Disgruntled": 0, "Passengers_Missions_Bulk": 300, "Passengers_Missions_VIP": 400, "Passengers_Missions_Delivered": 700, "Passengers_Missions_Ejected": 2 },
+            "Search_And_Rescue": { "SearchRescue_Traded": 40, "SearchRescue_Profit": 1200000, "SearchRescue_Count": 9, "Salvage_Legal_POI": 0, "Salvage_Legal_Settlements": 0, "Salvage_Illegal_POI": 0, "Salvage_Illegal_Settlements": 0, "Maglocks_Opened": 0, "Panels_Opened": 0, "Settlements_State_FireOut": 0, "Settlements_State_Reboot": 0 },
+            "TG_ENCOUNTERS": { "TG_ENCOUNTER_KILLED": 12, "TG_ENCOUNTER_TOTAL": 30, "TG_ENCOUNTER_TOTAL_LAST_SYSTEM": "HIP 22460", "TG_ENCOUNTER_TOTAL_LAST_TIMESTAMP": "3312-06-01 18:39", "TG_ENCOUNTER_TOTAL_LAST_SHIP": "Krait Mk II", "TG_SCOUT_COUNT": 80 },
+            "Crafting": { "Count_Of_Used_Engineers": 20, "Recipes_Generated": 900, "Recipes_Generated_Rank_1": 100, "Recipes_Generated_Rank_2": 100, "Recipes_Generated_Rank_3": 200, "Recipes_Generated_Rank_4": 200, "Recipes_Generated_Rank_5": 300, "Suit_Mods_Applied": 0, "Weapon_Mods_Applied": 0, "Suits_Upgraded": 0, "Weapons_Upgraded": 0, "Suits_Upgraded_Full": 0, "Weapons_Upgraded_Full": 0, "Suit_Mods_Applied_Full": 0, "Weapon_Mods_Applied_Full": 0 },
+            "Crew": { "NpcCrew_TotalWages": 90000000, "NpcCrew_Hired": 2, "NpcCrew_Fired": 1, "NpcCrew_Died": 0 },
+            "Multicrew": { "Multicrew_Time_Total": 3600, "Multicrew_Gunner_Time_Total": 1200, "Multicrew_Fighter_Time_Total": 600, "Multicrew_Credits_Total": 400000, "Multicrew_Fines_Total": 0 },
+            "Material_Trader_Stats": { "Trades_Completed": 80, "Materials_Traded": 2000, "Encoded_Materials_Traded": 700, "Raw_Materials_Traded": 600, "Grade_1_Materials_Traded": 200, "Grade_2_Materials_Traded": 300, "Grade_3_Materials_Traded": 400, "Grade_4_Materials_Traded": 500, "Grade_5_Materials_Traded": 600, "Assets_Traded_In": 0, "Assets_Traded_Out": 0 },
+            "CQC": { "CQC_Credits_Earned": 10000, "CQC_Time_Played": 1800, "CQC_KD": 0.75, "CQC_Kills": 3, "CQC_WL": 0.5 },
+            "FLEETCARRIER": { "FLEETCARRIER_EXPORT_TOTAL": 1000, "FLEETCARRIER_IMPORT_TOTAL": 2000, "FLEETCARRIER_TRADEPROFIT_TOTAL": 30000000, "FLEETCARRIER_TRADESPEND_TOTAL": 20000000, "FLEETCARRIER_STOLENPROFIT_TOTAL": 0, "FLEETCARRIER_STOLENSPEND_TOTAL": 0, "FLEETCARRIER_DISTANCE_TRAVELLED": "31000 LY", "FLEETCARRIER_TOTAL_JUMPS": 80, "FLEETCARRIER_SHIPYARD_SOLD": 0, "FLEETCARRIER_SHIPYARD_PROFIT": 0, "FLEETCARRIER_OUTFITTING_SOLD": 0, "FLEETCARRIER_OUTFITTING_PROFIT": 0, "FLEETCARRIER_REARM_TOTAL": 0, "FLEETCARRIER_REFUEL_TOTAL": 0, "FLEETCARRIER_REFUEL_PROFIT": 0, "FLEETCARRIER_REPAIRS_TOTAL": 0, "FLEETCARRIER_VOUCHERS_REDEEMED": 0, "FLEETCARRIER_VOUCHERS_PROFIT": 0 },
+            "Exobiology": { "Organic_Genus_Encountered": 20, "Organic_Species_Encountered": 90, "Organic_Variant_Encountered": 150, "Organic_Data_Profits": 2000000000, "Organic_Data": 300, "First_Logged_Profits": 1000000000, "First_Logged": 60, "Organic_Systems": 120, "Organic_Planets": 150, "Organic_Genus": 20, "Organic_Species": 90 }
+        }"#;
+        let Event::Statistics(statistics) = assert_read(json) else {
+            panic!("not statistics")
+        };
+        assert_eq!(statistics.bank_account.current_wealth, 8934823341);
+        assert_eq!(statistics.combat.bounties_claimed, 1200);
+        assert_eq!(statistics.exploration.time_played, 7200000);
+        assert_eq!(statistics.thargoid_encounters.scout_count, 80);
+        assert_eq!(statistics.cqc.kd, 0.75);
+        assert_eq!(statistics.exobiology.first_logged, 60);
+        assert_eq!(
+            round_trip(json)["FLEETCARRIER"]["FLEETCARRIER_DISTANCE_TRAVELLED"],
+            "31000 LY"
+        );
+
+        let Event::Statistics(statistics) = assert_read(
+            r#"{ "timestamp": "2018-08-08T12:00:00Z", "event": "Statistics", "Bank_Account": { "Current_Wealth": 3400000 }, "Exploration": { "Systems_Visited": 40 } }"#,
+        ) else {
+            panic!("not statistics")
+        };
+        assert_eq!(statistics.exploration.systems_visited, 40);
+        assert_eq!(statistics.exobiology.data_profits, 0);
+    }
+
+    /// A jump from charging the drive to dropping at the destination, and
+    /// what is done on the way
+    #[test]
+    fn jumping_and_cruising() {
+        let json = r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "StartJump", "JumpType": "Hyperspace", "Taxi": false, "StarSystem": "Col 285 Sector AG-O d6-122", "SystemAddress": 4207509891435, "StarClass": "N" }"#;
+        let Event::StartJump(start) = assert_read(json) else {
+            panic!("not a start jump")
+        };
+        assert_eq!(start.system_address, Some(4207509891435));
+        assert_eq!(round_trip(json)["SystemAddress"], 4207509891435_i64);
+
+        // Supercruise goes nowhere in particular.
+        let Event::StartJump(start) = assert_read(
+            r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "StartJump", "JumpType": "Supercruise", "Taxi": false }"#,
+        ) else {
+            panic!("not a start jump")
+        };
+        assert_eq!(start.ty, travel::FsdJumpType::Supercruise);
+        assert_eq!(start.system_name, None);
+
+        let json = r#"{ "timestamp": "2026-08-08T12:00:40Z", "event": "FSDJump", "Taxi": false, "Multicrew": false, "StarSystem": "Col 285 Sector AG-O d6-122", "SystemAddress": 4207509891435, "StarPos": [-75.0, 45.0, 61.0], "SystemAllegiance": "", "SystemEconomy": "$economy_None;", "SystemSecondEconomy": "$economy_None;", "SystemGovernment": "$government_None;", "SystemSecurity": "$GAlAXY_MAP_INFO_state_anarchy;", "Population": 0, "Body": "Col 285 Sector AG-O d6-122 A", "BodyID": 1, "BodyType": "Star", "JumpDist": 58.2, "FuelUsed": 4.1, "FuelLevel": 27.9 }"#;
+        let Event::FsdJump(jump) = assert_read(json) else {
+            panic!("not a jump")
+        };
+        assert_eq!(jump.body_id, Some(1));
+        assert_eq!(jump.body_type, Some(BodyType::Star));
+        assert_eq!(jump.taxi, Some(false));
+        round_trip_as(
+            json,
+            r#"{ "timestamp": "2026-08-08T12:00:40Z", "event": "FSDJump", "Taxi": false, "Multicrew": false, "StarSystem": "Col 285 Sector AG-O d6-122", "SystemAddress": 4207509891435, "StarPos": [-75.0, 45.0, 61.0], "Body": "Col 285 Sector AG-O d6-122 A", "BodyID": 1, "BodyType": "Star", "JumpDist": 58.2, "FuelUsed": 4.1, "FuelLevel": 27.9 }"#,
+        );
+
+        let json = r#"{ "timestamp": "2026-08-08T12:05:00Z", "event": "SupercruiseExit", "Taxi": false, "Multicrew": false, "StarSystem": "Diaguandri", "SystemAddress": 670417429889, "Body": "Ray Gateway", "BodyID": 29, "BodyType": "Station" }"#;
+        let Event::SupercruiseExit(exit) = assert_read(json) else {
+            panic!("not a supercruise exit")
+        };
+        assert_eq!(exit.body_id, 29);
+        assert_eq!(exit.body_type, BodyType::Station);
+        round_trip(json);
+
+        for json in [
+            r#"{ "timestamp": "2026-08-08T12:00:45Z", "event": "JetConeBoost", "BoostValue": 4.0 }"#,
+            r#"{ "timestamp": "2026-08-08T12:00:50Z", "event": "FuelScoop", "Scooped": 5.0, "Total": 32.0 }"#,
+            r#"{ "timestamp": "2026-08-08T12:01:00Z", "event": "ReservoirReplenished", "FuelMain": 31.2, "FuelReservoir": 0.63 }"#,
+            r#"{ "timestamp": "2026-08-08T12:01:30Z", "event": "SupercruiseEntry", "Taxi": false, "Multicrew": false, "StarSystem": "Diaguandri", "SystemAddress": 670417429889 }"#,
+            r#"{ "timestamp": "2018-08-08T12:01:30Z", "event": "SupercruiseEntry", "StarSystem": "Diaguandri" }"#,
+            r#"{ "timestamp": "2026-08-08T12:04:59Z", "event": "SupercruiseDestinationDrop", "Type": "$USS_Type_Salvage;", "Type_Localised": "Degraded emissions", "Threat": 2 }"#,
+            r#"{ "timestamp": "2026-08-08T12:04:59Z", "event": "SupercruiseDestinationDrop", "Type": "Ray Gateway", "Threat": 0, "MarketID": 3223343616 }"#,
+        ] {
+            assert_read(json);
+            round_trip(json);
+        }
+    }
+
+    /// Down on a planet and up again, and off in an SRV
+    #[test]
+    fn on_the_surface() {
+        let json = r#"{ "timestamp": "2026-08-08T12:10:00Z", "event": "Touchdown", "PlayerControlled": true, "Taxi": false, "Multicrew": false, "StarSystem": "HIP 22460", "SystemAddress": 594676084435, "Body": "HIP 22460 A 3", "BodyID": 12, "OnStation": false, "OnPlanet": true, "Latitude": -12.5, "Longitude": 101.25, "NearestDestination": "$SAA_Unknown_Signal:#type=$SAA_SignalType_Geological;:#index=3;", "NearestDestination_Localised": "Surface signal: Geological (3)" }"#;
+        let Event::Touchdown(touchdown) = assert_read(json) else {
+            panic!("not a touchdown")
+        };
+        assert_eq!(touchdown.body_name, "HIP 22460 A 3");
+        assert_eq!(touchdown.latitude, Some(-12.5));
+        round_trip(json);
+
+        // A taxi says nothing of where.
+        let Event::Touchdown(touchdown) = assert_read(
+            r#"{ "timestamp": "2026-08-08T12:10:00Z", "event": "Touchdown", "PlayerControlled": false, "Taxi": true, "Multicrew": false, "StarSystem": "HIP 22460", "SystemAddress": 594676084435, "Body": "HIP 22460 A 3", "BodyID": 12, "OnStation": false, "OnPlanet": true }"#,
+        ) else {
+            panic!("not a touchdown")
+        };
+        assert_eq!(touchdown.latitude, None);
+
+        let json = r#"{ "timestamp": "2026-08-08T12:30:00Z", "event": "Liftoff", "PlayerControlled": true, "Taxi": false, "Multicrew": false, "StarSystem": "HIP 22460", "SystemAddress": 594676084435, "Body": "HIP 22460 A 3", "BodyID": 12, "OnStation": false, "OnPlanet": true, "Latitude": -12.5, "Longitude": 101.25, "NearestDestination": "$SAA_Unknown_Signal:#type=$SAA_SignalType_Geological;:#index=3;", "NearestDestination_Localised": "Surface signal: Geological (3)" }"#;
+        let Event::Liftoff(liftoff) = assert_read(json) else {
+            panic!("not a liftoff")
+        };
+        assert_eq!(liftoff.body_id, 12);
+        assert_eq!(liftoff.longitude, Some(101.25));
+        round_trip(json);
+
+        // Nor does a dropship taking off.
+        let Event::Liftoff(liftoff) = assert_read(
+            r#"{ "timestamp": "2026-08-08T12:30:00Z", "event": "Liftoff", "PlayerControlled": false, "Taxi": true, "Multicrew": false, "StarSystem": "HIP 22460", "SystemAddress": 594676084435, "Body": "HIP 22460 A 3", "BodyID": 12, "OnStation": false, "OnPlanet": true }"#,
+        ) else {
+            panic!("not a liftoff")
+        };
+        assert_eq!(liftoff.nearest_destination, None);
+
+        let json = r#"{ "timestamp": "2026-08-08T12:11:00Z", "event": "LaunchSRV", "SRVType": "testbuggy", "SRVType_Localised": "SRV Scarab", "Loadout": "starter", "ID": 53, "PlayerControlled": true }"#;
+        let Event::LaunchSrv(launch) = assert_read(json) else {
+            panic!("not an SRV launched")
+        };
+        assert_eq!(launch.id, 53);
+        assert_eq!(round_trip(json)["event"], "LaunchSRV");
+
+        for json in [
+            r#"{ "timestamp": "2026-08-08T12:20:00Z", "event": "DockSRV", "SRVType": "testbuggy", "SRVType_Localised": "SRV Scarab", "ID": 53 }"#,
+            r#"{ "timestamp": "2019-08-08T12:20:00Z", "event": "SRVDestroyed", "ID": 53 }"#,
+        ] {
+            assert_read(json);
+            round_trip(json);
+        }
+    }
+
+    /// A system claimed and a station built up in it
+    #[test]
+    fn colonisation() {
+        for event in [
+            "ColonisationSystemClaim",
+            "ColonisationBeaconDeployed",
+            "ColonisationSystemClaimRelease",
+        ] {
+            let json = format!(
+                r#"{{
+                    "timestamp": "2026-08-08T12:00:00Z",
+                    "event": "{}",
+                    "StarSystem": "Col 285 Sector AG-O d6-122",
+                    "SystemAddress": 4207509891435
+                }}"#,
+                event
+            );
+            assert_read(&json);
+            round_trip(&json);
+        }
+
+        let json = r#"{
+            "timestamp": "2026-08-08T12:10:00Z",
+            "event": "ColonisationConstructionDepot",
+            "MarketID": 3960950786,
+            "ConstructionProgress": 0.0,
+            "ConstructionComplete": false,
+            "ConstructionFailed": false,
+            "ResourcesRequired": [
+                { "Name": "$aluminium_name;", "Name_Localised": "Aluminium", "RequiredAmount": 500, "ProvidedAmount": 0, "Payment": 3239 }
+            ]
+        }"#;
+        let Event::ColonisationConstructionDepot(depot) = assert_read(json)
+        else {
+            panic!("not a depot")
+        };
+        assert_eq!(depot.resources_required[0].remaining(), 500);
+        round_trip(json);
+
+        let json = r#"{ "timestamp": "2026-08-08T12:15:00Z", "event": "ColonisationContribution", "MarketID": 3960950786, "Contributions": [ { "Name": "$aluminium_name;", "Name_Localised": "Aluminium", "Amount": 320 } ] }"#;
+        let Event::ColonisationContribution(contribution) = assert_read(json)
+        else {
+            panic!("not a contribution")
+        };
+        assert_eq!(contribution.contributions[0].amount, 320);
+        round_trip(json);
+    }
 }
@@ -0,0 +1,403 @@
+//! Odyssey's commander on foot: getting about, suits and weapons, and the
+//! goods, components and data carried in the backpack
+//!
+//! Only written with [`Entry::odyssey`][crate::entry::Entry::odyssey] set.
+//! What the backpack and ship's locker hold as a whole is in
+//! [`locker`][crate::entry::locker].
+
+use crate::station::StationType;
+use serde::{Deserialize, Serialize};
+
+/// Aboard a ship or SRV, or off it
+///
+/// `Embark` and `Disembark` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Embark {
+    #[serde(rename = "SRV")]
+    pub srv: bool,
+    pub taxi: bool,
+    pub multicrew: bool,
+    /// The commander's own ship's, where it is one
+    #[serde(rename = "ID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_id: Option<u64>,
+    pub star_system: String,
+    pub system_address: i64,
+    pub body: String,
+    #[serde(rename = "BodyID")]
+    pub body_id: i16,
+    pub on_station: bool,
+    pub on_planet: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_type: Option<StationType>,
+    #[serde(rename = "MarketID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market_id: Option<i64>,
+}
+
+/// A seat booked on an Apex taxi or a Frontline Solutions dropship
+///
+/// `BookTaxi` and `BookDropship` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BookTransport {
+    pub cost: u64,
+    pub destination_system: String,
+    /// The station, settlement or conflict zone
+    pub destination_location: String,
+    /// Whether it is a ride away from where the commander is
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub retreat: bool,
+}
+
+/// A booked seat given up
+///
+/// `CancelTaxi` and `CancelDropship` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CancelTransport {
+    pub refund: u64,
+}
+
+/// A suit bought or sold
+///
+/// `BuySuit` and `SellSuit` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Suit {
+    /// e.g. `utilitysuit_class1`
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    #[serde(rename = "SuitID")]
+    pub suit_id: u64,
+    pub price: u64,
+    /// e.g. `suit_improvedradar`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suit_mods: Vec<String>,
+}
+
+/// A suit taken up a grade
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpgradeSuit {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    #[serde(rename = "SuitID")]
+    pub suit_id: u64,
+    /// The grade it is now, from 1 to 5
+    pub class: u8,
+    pub cost: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resources: Vec<UpgradeResource>,
+}
+
+/// Components spent in an [`UpgradeSuit`] or [`UpgradeWeapon`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpgradeResource {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub count: u64,
+}
+
+/// A suit and the weapons it carries, saved under a name
+///
+/// `CreateSuitLoadout`, `SwitchSuitLoadout` and `SuitLoadout`, which is
+/// written on loading a game and on leaving the ship, are all written the same
+/// way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuitLoadout {
+    #[serde(rename = "SuitID")]
+    pub suit_id: u64,
+    pub suit_name: String,
+    #[serde(rename = "SuitName_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suit_name_localised: Option<String>,
+    #[serde(default)]
+    pub suit_mods: Vec<String>,
+    #[serde(rename = "LoadoutID")]
+    pub loadout_id: u64,
+    pub loadout_name: String,
+    pub modules: Vec<SuitModule>,
+}
+
+/// A weapon in a [`SuitLoadout`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuitModule {
+    /// e.g. `PrimaryWeapon1`
+    pub slot_name: String,
+    #[serde(rename = "SuitModuleID")]
+    pub suit_module_id: u64,
+    pub module_name: String,
+    #[serde(rename = "ModuleName_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_name_localised: Option<String>,
+    pub class: u8,
+    #[serde(default)]
+    pub weapon_mods: Vec<String>,
+}
+
+/// A weapon bought
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyWeapon {
+    /// e.g. `wpn_m_assaultrifle_kinetic_fauto`
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub price: u64,
+    #[serde(rename = "SuitModuleID")]
+    pub suit_module_id: u64,
+    pub class: u8,
+    #[serde(default)]
+    pub weapon_mods: Vec<String>,
+}
+
+/// A weapon taken up a grade
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpgradeWeapon {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    #[serde(rename = "SuitModuleID")]
+    pub suit_module_id: u64,
+    /// The grade it is now, from 1 to 5
+    pub class: u8,
+    pub cost: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resources: Vec<UpgradeResource>,
+}
+
+/// Which of a [`Locker`][crate::entry::locker::Locker]'s lists something
+/// belongs in
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum MicroResourceType {
+    Item,
+    Component,
+    Consumable,
+    Data,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Something picked up or put down on foot, or one line of a
+/// [`BackpackChange`]
+///
+/// `CollectItems` and `DropItems` are written the same way, but for `Stolen`,
+/// which only the first has.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CarriedItem {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    #[serde(rename = "Type")]
+    pub kind: MicroResourceType,
+    /// Who it belongs to, which is `0` where it is the commander's own
+    #[serde(rename = "OwnerID")]
+    pub owner_id: u64,
+    #[serde(rename = "MissionID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<u64>,
+    pub count: u64,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stolen: bool,
+}
+
+/// One kind of goods, components or data changing hands at a bar
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MicroResourceCount {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub category: MicroResourceType,
+    pub count: u64,
+}
+
+/// Goods, components or data bought from or sold to a bartender
+///
+/// `BuyMicroResources` and `SellMicroResources` are written the same way, save
+/// that a purchase was once written for one kind alone, without a list.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MicroResourceSale {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    /// Credits, for everything
+    pub price: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub micro_resources: Vec<MicroResourceCount>,
+    /// The one kind bought, in the older way of writing it
+    #[serde(flatten)]
+    pub micro_resource: Option<MicroResourceCount>,
+}
+
+impl MicroResourceSale {
+    /// Every kind that changed hands, however it was written
+    pub fn micro_resources(&self) -> impl Iterator<Item = &MicroResourceCount> {
+        self.micro_resources.iter().chain(&self.micro_resource)
+    }
+}
+
+/// Some of one kind swapped for another at a bar
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct TradeMicroResources {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub offered: Vec<MicroResourceCount>,
+    pub total_count: u64,
+    pub received: String,
+    #[serde(rename = "Received_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub received_localised: Option<String>,
+    pub category: MicroResourceType,
+    pub count: u64,
+}
+
+/// Things moved between the backpack and the ship's locker
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct TransferMicroResources {
+    pub transfers: Vec<MicroResourceTransfer>,
+}
+
+/// One kind moved in a [`TransferMicroResources`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MicroResourceTransfer {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub category: MicroResourceType,
+    pub locker_old_count: u64,
+    pub locker_new_count: u64,
+    pub direction: MicroResourceDirection,
+}
+
+/// Where a [`MicroResourceTransfer`] went
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MicroResourceDirection {
+    ToShipLocker,
+    ToBackpack,
+}
+
+/// A medkit, energy cell, grenade or the like used up
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UseConsumable {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    #[serde(rename = "Type")]
+    pub kind: MicroResourceType,
+}
+
+/// A plant or animal looked at with the genetic sampler
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ScanOrganic {
+    pub scan_type: OrganicScanType,
+    /// Symbolic, e.g. `$Codex_Ent_Bacterial_Genus_Name;`
+    pub genus: String,
+    #[serde(rename = "Genus_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genus_localised: Option<String>,
+    pub species: String,
+    #[serde(rename = "Species_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub species_localised: Option<String>,
+    /// Only since the species' colours were told apart
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    #[serde(rename = "Variant_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_localised: Option<String>,
+    pub system_address: i64,
+    /// The body's ID, for all it is named `Body`
+    #[serde(rename = "Body")]
+    pub body_id: i16,
+}
+
+/// How far along a species' three samples a [`ScanOrganic`] is
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrganicScanType {
+    /// The first sample
+    Log,
+    /// The second
+    Sample,
+    /// The third, after which it can be sold
+    Analyse,
+}
+
+/// Samples analysed sold at Vista Genomics
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SellOrganicData {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub bio_data: Vec<BioData>,
+}
+
+impl SellOrganicData {
+    /// Credits paid, bonuses for first discoveries included
+    pub fn total(&self) -> u64 {
+        self.bio_data
+            .iter()
+            .map(|data| data.value + data.bonus)
+            .sum()
+    }
+}
+
+/// One species sold in a [`SellOrganicData`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BioData {
+    pub genus: String,
+    #[serde(rename = "Genus_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub genus_localised: Option<String>,
+    pub species: String,
+    #[serde(rename = "Species_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub species_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    #[serde(rename = "Variant_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variant_localised: Option<String>,
+    pub value: u64,
+    /// Paid over the value, for being the first to sample it
+    pub bonus: u64,
+}
+
+/// The backpack's contents changed, other than by picking up or putting down
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BackpackChange {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<CarriedItem>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<CarriedItem>,
+}
@@ -0,0 +1,90 @@
+use crate::system::Power;
+use serde::{Deserialize, Serialize};
+
+/// Where the commander stands with their power, written on loading a game
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Powerplay {
+    pub power: Power,
+    pub rank: u32,
+    pub merits: u64,
+    /// Seconds since pledging
+    pub time_pledged: u64,
+    /// Only in the first Powerplay, which had them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub votes: Option<u64>,
+}
+
+/// Pledged to a power
+///
+/// `PowerplayJoin` and `PowerplayLeave` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerplayJoin {
+    pub power: Power,
+}
+
+/// Gone over from one power to another, keeping some of the merits earned
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerplayDefect {
+    pub from_power: Power,
+    pub to_power: Power,
+}
+
+/// Merits earned for a power, however they were earned
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerplayMerits {
+    pub power: Power,
+    pub merits_gained: u64,
+    pub total_merits: u64,
+}
+
+/// A new rank with a power
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerplayRank {
+    pub power: Power,
+    pub rank: u32,
+}
+
+/// Powerplay goods picked up or handed in
+///
+/// `PowerplayCollect` and `PowerplayDeliver` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerplayCargo {
+    pub power: Power,
+    /// Symbolic, e.g. `powerspyware`
+    #[serde(rename = "Type")]
+    pub commodity: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    pub count: u64,
+}
+
+/// The weekly stipend from a power
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerplaySalary {
+    pub power: Power,
+    pub amount: u64,
+}
+
+/// Powerplay vouchers handed in, for the systems they were earned in
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerplayVoucher {
+    pub power: Power,
+    pub systems: Vec<String>,
+}
+
+/// Paid to get Powerplay goods sooner than the timer allows
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerplayFastTrack {
+    pub power: Power,
+    pub cost: u64,
+}
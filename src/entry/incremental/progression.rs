@@ -0,0 +1,364 @@
+//! The commander's ranks, how near they are to the next, and how the
+//! superpowers regard them
+//!
+//! The game writes every rank as a number from the bottom up. Each has an enum
+//! here, read and written through that number, which shows as the rank's name
+//! in the game. The few events that give someone else's rank give its name,
+//! and read it through [`by_name`].
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// Every rank, written on loading a game
+///
+/// Soldier and exobiologist ranks came with Odyssey, and are left out before.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Rank {
+    pub combat: CombatRank,
+    pub trade: TradeRank,
+    pub explore: ExploreRank,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soldier: Option<SoldierRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exobiologist: Option<ExobiologistRank>,
+    pub empire: EmpireRank,
+    pub federation: FederationRank,
+    #[serde(rename = "CQC")]
+    pub cqc: CqcRank,
+}
+
+/// How far through each rank the commander is, in percent, written on loading
+/// a game
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Progress {
+    pub combat: u8,
+    pub trade: u8,
+    pub explore: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soldier: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exobiologist: Option<u8>,
+    pub empire: u8,
+    pub federation: u8,
+    #[serde(rename = "CQC")]
+    pub cqc: u8,
+}
+
+/// A rank gained, which is the only one given
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Promotion {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub combat: Option<CombatRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trade: Option<TradeRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub explore: Option<ExploreRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soldier: Option<SoldierRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exobiologist: Option<ExobiologistRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empire: Option<EmpireRank>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub federation: Option<FederationRank>,
+    #[serde(rename = "CQC")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cqc: Option<CqcRank>,
+}
+
+/// How each superpower regards the commander, written on loading a game
+///
+/// Each is from -100, hostile, to 100, allied, and left out where the
+/// commander has never had dealings with them.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Reputation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empire: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub federation: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub independent: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alliance: Option<f64>,
+}
+
+// Every rank is an enum read and written through its number, which orders it,
+// and shown and parsed by its name in the game. A rank added since is kept by
+// its number. Squadron ranks are made the same way.
+macro_rules! rank {
+    ($(#[$meta:meta])* $rank:ident {
+        $($number:literal => $variant:ident $name:literal,)*
+    }) => {
+        $(#[$meta])*
+        #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+        #[serde(from = "u8", into = "u8")]
+        pub enum $rank {
+            $($variant,)*
+            /// A rank added since, by its number
+            Other(u8),
+        }
+
+        impl From<u8> for $rank {
+            fn from(rank: u8) -> Self {
+                match rank {
+                    $($number => $rank::$variant,)*
+                    other => $rank::Other(other),
+                }
+            }
+        }
+
+        impl From<$rank> for u8 {
+            fn from(rank: $rank) -> Self {
+                match rank {
+                    $($rank::$variant => $number,)*
+                    $rank::Other(other) => other,
+                }
+            }
+        }
+
+        impl PartialOrd for $rank {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $rank {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                u8::from(*self).cmp(&u8::from(*other))
+            }
+        }
+
+        impl std::fmt::Display for $rank {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                match self {
+                    $($rank::$variant => f.write_str($name),)*
+                    $rank::Other(other) => write!(f, "Rank {}", other),
+                }
+            }
+        }
+
+        impl std::str::FromStr for $rank {
+            type Err = $crate::entry::incremental::progression::ParseRankError;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                match name {
+                    $($name => Ok($rank::$variant),)*
+                    other => other
+                        .strip_prefix("Rank ")
+                        .and_then(|number| number.parse::<u8>().ok())
+                        .map($rank::from)
+                        .ok_or_else(|| {
+                            $crate::entry::incremental::progression::ParseRankError(
+                                other.to_string(),
+                            )
+                        }),
+                }
+            }
+        }
+    };
+}
+pub(crate) use rank;
+
+/// A name that is none of a rank's
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRankError(pub String);
+
+impl fmt::Display for ParseRankError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no rank is named {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseRankError {}
+
+/// Read and write a rank by its name, e.g. `Mostly Harmless`, rather than its
+/// number
+///
+/// For a field that may be left out, so it wants `default` alongside.
+pub mod by_name {
+    use super::*;
+
+    pub fn serialize<S, R>(rank: &Option<R>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        R: fmt::Display,
+    {
+        match rank {
+            Some(rank) => s.collect_str(rank),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D, R>(d: D) -> Result<Option<R>, D::Error>
+    where
+        D: Deserializer<'de>,
+        R: FromStr<Err = ParseRankError>,
+    {
+        Option::<String>::deserialize(d)?
+            .map(|name| name.parse().map_err(de::Error::custom))
+            .transpose()
+    }
+}
+
+rank! {
+    CombatRank {
+        0 => Harmless "Harmless",
+        1 => MostlyHarmless "Mostly Harmless",
+        2 => Novice "Novice",
+        3 => Competent "Competent",
+        4 => Expert "Expert",
+        5 => Master "Master",
+        6 => Dangerous "Dangerous",
+        7 => Deadly "Deadly",
+        8 => Elite "Elite",
+        9 => EliteI "Elite I",
+        10 => EliteII "Elite II",
+        11 => EliteIII "Elite III",
+        12 => EliteIV "Elite IV",
+        13 => EliteV "Elite V",
+    }
+}
+
+rank! {
+    TradeRank {
+        0 => Penniless "Penniless",
+        1 => MostlyPenniless "Mostly Penniless",
+        2 => Peddler "Peddler",
+        3 => Dealer "Dealer",
+        4 => Merchant "Merchant",
+        5 => Broker "Broker",
+        6 => Entrepreneur "Entrepreneur",
+        7 => Tycoon "Tycoon",
+        8 => Elite "Elite",
+        9 => EliteI "Elite I",
+        10 => EliteII "Elite II",
+        11 => EliteIII "Elite III",
+        12 => EliteIV "Elite IV",
+        13 => EliteV "Elite V",
+    }
+}
+
+rank! {
+    ExploreRank {
+        0 => Aimless "Aimless",
+        1 => MostlyAimless "Mostly Aimless",
+        2 => Scout "Scout",
+        3 => Surveyor "Surveyor",
+        4 => Trailblazer "Trailblazer",
+        5 => Pathfinder "Pathfinder",
+        6 => Ranger "Ranger",
+        7 => Pioneer "Pioneer",
+        8 => Elite "Elite",
+        9 => EliteI "Elite I",
+        10 => EliteII "Elite II",
+        11 => EliteIII "Elite III",
+        12 => EliteIV "Elite IV",
+        13 => EliteV "Elite V",
+    }
+}
+
+rank! {
+    /// The mercenary rank, earned on foot
+    SoldierRank {
+        0 => Defenceless "Defenceless",
+        1 => MostlyDefenceless "Mostly Defenceless",
+        2 => Rookie "Rookie",
+        3 => Soldier "Soldier",
+        4 => Gunslinger "Gunslinger",
+        5 => Warrior "Warrior",
+        6 => Gladiator "Gladiator",
+        7 => Deadeye "Deadeye",
+        8 => Elite "Elite",
+        9 => EliteI "Elite I",
+        10 => EliteII "Elite II",
+        11 => EliteIII "Elite III",
+        12 => EliteIV "Elite IV",
+        13 => EliteV "Elite V",
+    }
+}
+
+rank! {
+    ExobiologistRank {
+        0 => Directionless "Directionless",
+        1 => MostlyDirectionless "Mostly Directionless",
+        2 => Compiler "Compiler",
+        3 => Collector "Collector",
+        4 => Cataloguer "Cataloguer",
+        5 => Taxonomist "Taxonomist",
+        6 => Ecologist "Ecologist",
+        7 => Geneticist "Geneticist",
+        8 => Elite "Elite",
+        9 => EliteI "Elite I",
+        10 => EliteII "Elite II",
+        11 => EliteIII "Elite III",
+        12 => EliteIV "Elite IV",
+        13 => EliteV "Elite V",
+    }
+}
+
+rank! {
+    /// Close quarters combat, the arena
+    CqcRank {
+        0 => Helpless "Helpless",
+        1 => MostlyHelpless "Mostly Helpless",
+        2 => Amateur "Amateur",
+        3 => SemiProfessional "Semi Professional",
+        4 => Professional "Professional",
+        5 => Champion "Champion",
+        6 => Hero "Hero",
+        7 => Legend "Legend",
+        8 => Elite "Elite",
+        9 => EliteI "Elite I",
+        10 => EliteII "Elite II",
+        11 => EliteIII "Elite III",
+        12 => EliteIV "Elite IV",
+        13 => EliteV "Elite V",
+    }
+}
+
+rank! {
+    /// The Federal Navy's
+    FederationRank {
+        0 => None "None",
+        1 => Recruit "Recruit",
+        2 => Cadet "Cadet",
+        3 => Midshipman "Midshipman",
+        4 => PettyOfficer "Petty Officer",
+        5 => ChiefPettyOfficer "Chief Petty Officer",
+        6 => WarrantOfficer "Warrant Officer",
+        7 => Ensign "Ensign",
+        8 => Lieutenant "Lieutenant",
+        9 => LieutenantCommander "Lieutenant Commander",
+        10 => PostCommander "Post Commander",
+        11 => PostCaptain "Post Captain",
+        12 => RearAdmiral "Rear Admiral",
+        13 => ViceAdmiral "Vice Admiral",
+        14 => Admiral "Admiral",
+    }
+}
+
+rank! {
+    /// The Empire's nobility
+    EmpireRank {
+        0 => None "None",
+        1 => Outsider "Outsider",
+        2 => Serf "Serf",
+        3 => Master "Master",
+        4 => Squire "Squire",
+        5 => Knight "Knight",
+        6 => Lord "Lord",
+        7 => Baron "Baron",
+        8 => Viscount "Viscount",
+        9 => Count "Count",
+        10 => Earl "Earl",
+        11 => Marquis "Marquis",
+        12 => Duke "Duke",
+        13 => Prince "Prince",
+        14 => King "King",
+    }
+}
@@ -0,0 +1,44 @@
+use super::progression::rank;
+use serde::{Deserialize, Serialize};
+
+/// The commander's squadron and rank in it, written on loading a game
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SquadronStartup {
+    pub squadron_name: String,
+    pub current_rank: SquadronRank,
+}
+
+/// Something that happened between the commander and a squadron
+///
+/// Written the same way for `SquadronCreated`, `AppliedToSquadron`,
+/// `InvitedToSquadron`, `JoinedSquadron`, `LeftSquadron`,
+/// `KickedFromSquadron`, `DisbandedSquadron` and `SharedBookmarkToSquadron`,
+/// which name the squadron and nothing else.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Squadron {
+    pub squadron_name: String,
+}
+
+/// A rank lost or gained in a squadron
+///
+/// `SquadronDemotion` and `SquadronPromotion` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SquadronRankChange {
+    pub squadron_name: String,
+    pub old_rank: SquadronRank,
+    pub new_rank: SquadronRank,
+}
+
+rank! {
+    /// A rank in a squadron, sent as a number from the bottom up
+    SquadronRank {
+        0 => Rookie "Rookie",
+        1 => Agent "Agent",
+        2 => Officer "Officer",
+        3 => SeniorOfficer "Senior Officer",
+        4 => Leader "Leader",
+    }
+}
@@ -1,6 +1,7 @@
-use serde::Deserialize;
+pub use crate::entry::cargo::{Cargo, Manifest, Vessel};
+use serde::{Deserialize, Serialize, Serializer};
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Fileheader {
     pub part: u8,
     pub language: String,
@@ -8,7 +9,7 @@ pub struct Fileheader {
     pub build: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct NewCommander {
     #[serde(flatten)]
@@ -16,44 +17,63 @@ pub struct NewCommander {
     pub package: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Commander {
     #[serde(alias = "Commander")]
     pub name: String,
     /// [`None`] in a journal older than the 3.0 client, which never wrote one
     #[serde(rename = "FID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub fid: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct LoadGame {
+    /// Named `Commander` here where the event of that name says `Name`
     #[serde(flatten)]
+    #[serde(serialize_with = "loaded_commander")]
     pub commander: Option<Commander>,
     #[serde(flatten)]
     pub ship: Option<Ship>,
     pub horizons: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub game_mode: Option<GameMode>,
     pub credits: u64,
     pub loan: u64,
 }
 
-#[derive(Deserialize, Debug)]
+/// Write a [`LoadGame`]'s commander under the name that event gives it
+fn loaded_commander<S: Serializer>(
+    commander: &Option<Commander>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct Loaded<'a> {
+        #[serde(rename = "Commander")]
+        name: &'a str,
+        #[serde(rename = "FID")]
+        fid: &'a Option<String>,
+    }
+
+    commander
+        .as_ref()
+        .map(|commander| Loaded {
+            name: &commander.name,
+            fid: &commander.fid,
+        })
+        .serialize(s)
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum GameMode {
     Open,
     Group,
     Solo,
 }
 
-#[derive(Deserialize, Debug)]
-pub enum Vessel {
-    #[serde(rename = "SRV")]
-    Srv,
-    Ship,
-}
-
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Ship {
     #[serde(rename = "Ship")]
     pub model: String,
@@ -65,34 +85,136 @@ pub struct Ship {
     pub ident: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
-pub struct Manifest {
-    pub vessel: Vessel,
-    #[serde(default)]
-    pub inventory: Vec<Cargo>,
+pub struct Materials {
+    pub raw: Vec<Material>,
+    pub manufactured: Vec<Material>,
+    pub encoded: Vec<Material>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
-pub struct Cargo {
+pub struct Material {
     pub name: String,
     pub count: u64,
-    pub stolen: u64,
-    pub mission_id: Option<u64>,
 }
 
-#[derive(Deserialize, Debug)]
+/// A ship as fitted, written on loading a game and after any change to it
+///
+/// Everything needed to know what the ship does, as against
+/// [`ModulesInfo`][crate::entry::modules_info::ModulesInfo], which gives
+/// what each module draws and nothing else.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
-pub struct Materials {
-    pub raw: Vec<Material>,
-    pub manufactured: Vec<Material>,
-    pub encoded: Vec<Material>,
+pub struct Loadout {
+    #[serde(flatten)]
+    pub ship: Ship,
+    /// Credits, where the game gave it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hull_value: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modules_value: Option<u64>,
+    /// From 0 to 1
+    pub hull_health: f64,
+    /// Tonnes, with no fuel or cargo aboard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unladen_mass: Option<f64>,
+    pub cargo_capacity: u32,
+    /// Light years, laden with a full tank and nothing else
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_jump_range: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuel_capacity: Option<FuelCapacity>,
+    /// What it costs to buy the ship back after losing it
+    pub rebuy: u64,
+    /// Whether the ship is wanted for being stolen goods itself
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hot: bool,
+    pub modules: Vec<LoadoutModule>,
 }
 
-#[derive(Deserialize, Debug)]
+/// Tonnes of fuel a [`Loadout`] can carry
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
-pub struct Material {
-    pub name: String,
-    pub count: u64,
+pub struct FuelCapacity {
+    pub main: f64,
+    /// What the reactor burns from, apart from the tanks
+    pub reserve: f64,
+}
+
+/// One module fitted in a [`Loadout`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct LoadoutModule {
+    /// Where it is fitted, e.g. `MediumHardpoint1` or `Slot03_Size4`
+    pub slot: String,
+    /// Symbolic and lowercase, e.g. `hpt_pulselaser_gimbal_medium`
+    pub item: String,
+    /// Whether it is switched on
+    pub on: bool,
+    /// The power group it is shed in, from 0, which is shed last
+    pub priority: u8,
+    /// From 0 to 1
+    pub health: f64,
+    /// Credits, left out for a module that came with the ship
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ammo_in_clip: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ammo_in_hopper: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engineering: Option<Engineering>,
+}
+
+/// What an engineer did to a [`LoadoutModule`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Engineering {
+    /// [`None`] for a module bought already modified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engineer: Option<String>,
+    #[serde(rename = "EngineerID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub engineer_id: Option<u64>,
+    #[serde(rename = "BlueprintID")]
+    pub blueprint_id: u64,
+    /// e.g. `Weapon_Overcharged`
+    pub blueprint_name: String,
+    /// The grade, from 1 to 5
+    pub level: u8,
+    /// How far through the grade it has been rolled, from 0 to 1
+    pub quality: f64,
+    /// e.g. `special_auto_loader`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental_effect: Option<String>,
+    #[serde(rename = "ExperimentalEffect_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental_effect_localised: Option<String>,
+    #[serde(default)]
+    pub modifiers: Vec<Modifier>,
+}
+
+/// One property an [`Engineering`] changed, and what it was before
+///
+/// Most are numbers, given with what they were before. A few are words, such
+/// as a weapon's firing mode, and are given only as they are now.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Modifier {
+    /// e.g. `DamagePerSecond`
+    pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_value: Option<f64>,
+    /// `1` where a lower value is the better one, as the game writes it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub less_is_good: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_str: Option<String>,
+    #[serde(rename = "ValueStr_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_str_localised: Option<String>,
 }
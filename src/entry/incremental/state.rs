@@ -0,0 +1,426 @@
+//! Where the commander is, in what, and with how much, folded from the journal
+//!
+//! The game writes the whole picture only on loading a game, and from then on
+//! writes what changes. [`CommanderState`] takes in the entries in the order
+//! they were written and keeps the picture up, so the same journal always
+//! comes out the same.
+
+use crate::entry::cargo::{Manifest, Vessel};
+use crate::entry::incremental::engineering::ContributionType;
+use crate::entry::incremental::startup::Ship;
+use crate::entry::incremental::{Event, Fold};
+use crate::entry::Entry;
+use std::collections::BTreeMap;
+
+/// The commander as of the last entry taken in
+///
+/// Nothing is known until the game says so: everything starts empty, and a
+/// journal read from its first entry has it all by the first `Location`.
+/// Credits are only counted from a `LoadGame`, since a change to a sum not yet
+/// known is no use.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CommanderState {
+    pub commander: Option<String>,
+    pub system: Option<String>,
+    pub system_address: Option<i64>,
+    /// The station docked at
+    pub station: Option<String>,
+    pub market_id: Option<i64>,
+    /// The body nearest, or landed on, by name
+    pub body: Option<String>,
+    pub docked: bool,
+    pub landed: bool,
+    /// Unknown for a moment after a ship is swapped or bought, until its
+    /// `Loadout`
+    pub ship: Option<Ship>,
+    pub credits: Option<u64>,
+    /// Tonnes of each commodity in the ship's hold, by its name in lowercase,
+    /// e.g. `gold`
+    ///
+    /// Exact as of the last manifest that listed the hold, and kept up from
+    /// buying, selling, scooping, refining, jettisoning, handing cargo to an
+    /// engineer or a construction depot, and dying since. Cargo for a mission
+    /// is counted only from a manifest: taking it on at a `CargoDepot`, and a
+    /// `MissionCompleted` handing it over or paying in it, change nothing
+    /// here. The `Cargo` events written since 3.3 give only the count, so
+    /// such changes wait for the next manifest that lists the hold.
+    pub cargo: BTreeMap<String, u64>,
+}
+
+impl Fold for CommanderState {
+    /// A `LoadGame` starts the commander afresh, where anything else moves on
+    /// only what it speaks of
+    fn apply(&mut self, entry: &Entry<Event>) {
+        match &entry.event {
+            Event::LoadGame(game) => {
+                *self = CommanderState {
+                    commander: game.commander.as_ref().map(|c| c.name.clone()),
+                    ship: game.ship.clone(),
+                    credits: Some(game.credits),
+                    ..CommanderState::default()
+                };
+            }
+            Event::Location(location) => {
+                self.system = Some(location.system.name.clone());
+                self.system_address = Some(location.system.address);
+                self.body = location.body_name.clone();
+                self.docked = location.docked;
+                self.landed = !location.docked && location.latitude.is_some();
+                let station = location.station.as_ref().filter(|_| self.docked);
+                self.station = station.map(|s| s.name.clone());
+                self.market_id = station.and_then(|s| s.market_id);
+            }
+            Event::FsdJump(jump) => {
+                self.system = Some(jump.system.name.clone());
+                self.system_address = Some(jump.system.address);
+                self.body = jump.body.clone();
+                self.station = None;
+                self.market_id = None;
+                self.docked = false;
+                self.landed = false;
+            }
+            // The carrier is a station, and the commander is aboard it.
+            Event::CarrierJump(jump) => {
+                self.system = Some(jump.system.name.clone());
+                self.system_address = Some(jump.system.address);
+                self.body = jump.body_name.clone();
+                self.docked = jump.docked.unwrap_or(true);
+                self.landed = false;
+                let station = jump.station.as_ref().filter(|_| self.docked);
+                self.station = station.map(|s| s.name.clone());
+                self.market_id = station.and_then(|s| s.market_id);
+            }
+            Event::Docked(docked) => {
+                self.system = Some(docked.system_name.clone());
+                self.system_address = Some(docked.system_address);
+                self.station = Some(docked.station.name.clone());
+                self.market_id = docked.station.market_id;
+                self.docked = true;
+            }
+            Event::Undocked(_) => {
+                self.station = None;
+                self.market_id = None;
+                self.docked = false;
+            }
+            Event::Touchdown(touchdown) => {
+                self.body = Some(touchdown.body_name.clone());
+                self.landed = true;
+            }
+            Event::Liftoff(_) => self.landed = false,
+            Event::ApproachBody(approach) => {
+                self.body = Some(approach.name.clone());
+            }
+            Event::SupercruiseExit(exit) => self.body = Some(exit.body.clone()),
+            Event::SupercruiseEntry(_) | Event::LeaveBody(_) => {
+                self.body = None
+            }
+
+            Event::Loadout(loadout) => self.ship = Some(loadout.ship.clone()),
+            Event::ShipyardSwap(_) => self.ship = None,
+
+            Event::Cargo(manifest) => self.set_cargo(manifest),
+            Event::MarketBuy(buy) => {
+                self.load(&buy.commodity, buy.count);
+                self.spend(buy.total_cost);
+            }
+            Event::MarketSell(sell) => {
+                self.unload(&sell.commodity, sell.count);
+                self.earn(sell.total_sale);
+            }
+            Event::SearchAndRescue(rescue) => {
+                self.unload(&rescue.name, rescue.count);
+                self.earn(rescue.reward);
+            }
+            Event::CollectCargo(collect) => self.load(&collect.commodity, 1),
+            Event::MiningRefined(refined) => self.load(&refined.commodity, 1),
+            Event::EjectCargo(eject) => {
+                self.unload(&eject.commodity, eject.count)
+            }
+            Event::ColonisationContribution(contribution) => {
+                for given in &contribution.contributions {
+                    self.unload(&given.name, given.amount);
+                }
+            }
+
+            Event::BuyTradeData(data) => self.spend(data.cost),
+            Event::BuyExplorationData(data) => self.spend(data.cost),
+            Event::SellExplorationData(data) => self.earn(data.total_earnings),
+            Event::MultiSellExplorationData(data) => {
+                self.earn(data.total_earnings)
+            }
+            Event::SellOrganicData(data) => self.earn(data.total()),
+            Event::RefuelAll(refuel) | Event::RefuelPartial(refuel) => {
+                self.spend(refuel.cost)
+            }
+            Event::Repair(repair) => self.spend(repair.cost),
+            Event::RepairAll(repair) => self.spend(repair.cost),
+            Event::RestockVehicle(restock) => self.spend(restock.cost),
+            Event::BuyAmmo(ammo) => self.spend(ammo.cost),
+            Event::BuyDrones(drones) => self.spend(drones.total_cost),
+            Event::SellDrones(drones) => self.earn(drones.total_sale),
+            Event::ShipyardBuy(buy) => {
+                self.earn(buy.sell_price.unwrap_or(0));
+                self.spend(buy.ship_price);
+                self.ship = None;
+            }
+            Event::ShipyardSell(sell) => self.earn(sell.ship_price),
+            Event::SellShipOnRebuy(sell) => self.earn(sell.ship_price),
+            Event::ShipyardTransfer(transfer) => {
+                self.spend(transfer.transfer_price)
+            }
+            Event::ModuleBuy(buy) => {
+                self.earn(buy.sell_price.unwrap_or(0));
+                self.spend(buy.buy_price);
+            }
+            Event::ModuleSell(sell) => self.earn(sell.sell_price),
+            Event::ModuleSellRemote(sell) => self.earn(sell.sell_price),
+            Event::ModuleStore(store) => self.spend(store.cost.unwrap_or(0)),
+            Event::ModuleRetrieve(retrieve) => {
+                self.spend(retrieve.cost.unwrap_or(0))
+            }
+            Event::FetchRemoteModule(fetch) => self.spend(fetch.transfer_cost),
+            Event::PayFines(fines) | Event::PayBounties(fines) => {
+                self.spend(fines.amount)
+            }
+            Event::PayLegacyFines(fines) => self.spend(fines.amount),
+            Event::Died(_) => self.cargo.clear(),
+            Event::Resurrect(resurrect) => self.spend(resurrect.cost),
+            Event::RedeemVoucher(voucher) => self.earn(voucher.amount),
+            Event::MissionCompleted(mission) => {
+                self.earn(mission.reward.unwrap_or(0));
+                self.spend(mission.donated.unwrap_or(0));
+            }
+            Event::CommunityGoalReward(goal) => self.earn(goal.reward),
+            Event::CrewHire(hire) => self.spend(hire.cost),
+            Event::NpcCrewPaidWage(wage) => self.spend(wage.amount),
+            Event::EngineerContribution(contribution) => {
+                match (&contribution.kind, &contribution.commodity) {
+                    (ContributionType::Credits, _) => {
+                        self.spend(contribution.quantity)
+                    }
+                    (ContributionType::Commodity, Some(commodity)) => {
+                        self.unload(commodity, contribution.quantity)
+                    }
+                    _ => {}
+                }
+            }
+            Event::PowerplaySalary(salary) => self.earn(salary.amount),
+            Event::PowerplayFastTrack(fast_track) => {
+                self.spend(fast_track.cost)
+            }
+            Event::CarrierBuy(carrier) => self.spend(carrier.price),
+            Event::CarrierBankTransfer(transfer) if self.credits.is_some() => {
+                self.credits = Some(transfer.player_balance.max(0) as u64);
+            }
+            Event::BookTaxi(booking) | Event::BookDropship(booking) => {
+                self.spend(booking.cost)
+            }
+            Event::CancelTaxi(cancel) | Event::CancelDropship(cancel) => {
+                self.earn(cancel.refund)
+            }
+            Event::BuySuit(suit) => self.spend(suit.price),
+            Event::SellSuit(suit) => self.earn(suit.price),
+            Event::UpgradeSuit(upgrade) => self.spend(upgrade.cost),
+            Event::BuyWeapon(weapon) => self.spend(weapon.price),
+            Event::UpgradeWeapon(upgrade) => self.spend(upgrade.cost),
+            Event::BuyMicroResources(sale) => self.spend(sale.price),
+            Event::SellMicroResources(sale) => self.earn(sale.price),
+            _ => {}
+        }
+    }
+}
+
+impl CommanderState {
+    /// Take the hold from a manifest, such as `Cargo.json`
+    ///
+    /// Only a manifest of the ship's hold that lists it, or says it is empty,
+    /// changes anything.
+    pub fn set_cargo(&mut self, manifest: &Manifest) {
+        if manifest.vessel != Vessel::Ship
+            || manifest.inventory.is_empty() && manifest.count.unwrap_or(0) > 0
+        {
+            return;
+        }
+        self.cargo.clear();
+        for cargo in &manifest.inventory {
+            self.load(&cargo.name, cargo.count);
+        }
+    }
+
+    fn earn(&mut self, credits: u64) {
+        if let Some(balance) = &mut self.credits {
+            *balance = balance.saturating_add(credits);
+        }
+    }
+
+    fn spend(&mut self, credits: u64) {
+        if let Some(balance) = &mut self.credits {
+            *balance = balance.saturating_sub(credits);
+        }
+    }
+
+    fn load(&mut self, commodity: &str, tonnes: u64) {
+        if tonnes > 0 {
+            *self.cargo.entry(commodity_name(commodity)).or_default() += tonnes;
+        }
+    }
+
+    fn unload(&mut self, commodity: &str, tonnes: u64) {
+        let name = commodity_name(commodity);
+        if let Some(count) = self.cargo.get_mut(&name) {
+            *count = count.saturating_sub(tonnes);
+            if *count == 0 {
+                self.cargo.remove(&name);
+            }
+        }
+    }
+}
+
+/// A commodity by the name a manifest gives it, where some events give the
+/// symbol instead, e.g. `$painite_name;` for `painite`
+fn commodity_name(commodity: &str) -> String {
+    let name = commodity.to_lowercase();
+    match name
+        .strip_prefix('$')
+        .and_then(|n| n.strip_suffix("_name;"))
+    {
+        Some(name) => name.to_string(),
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::fold;
+
+    const SESSION: &[&str] = &[
+        r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "LoadGame", "FID": "F1234567", "Commander": "Jameson", "Horizons": true, "Ship": "CobraMkIII", "ShipID": 1, "ShipName": "Gutamaya Bane", "ShipIdent": "JA-01", "GameMode": "Solo", "Credits": 1000000, "Loan": 0 }"#,
+        r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Location", "Docked": true, "StationName": "Abraham Lincoln", "StationType": "Orbis", "MarketID": 128016640, "StarSystem": "Sol", "SystemAddress": 10477373803, "StarPos": [0.0, 0.0, 0.0], "Body": "Earth", "BodyID": 3, "BodyType": "Planet" }"#,
+        r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "Cargo", "Vessel": "Ship", "Count": 0, "Inventory": [] }"#,
+        r#"{ "timestamp": "2026-08-08T12:01:00Z", "event": "MarketBuy", "MarketID": 128016640, "Type": "gold", "Count": 10, "BuyPrice": 9000, "TotalCost": 90000 }"#,
+        r#"{ "timestamp": "2026-08-08T12:02:00Z", "event": "RefuelAll", "Cost": 120, "Amount": 2.4 }"#,
+        r#"{ "timestamp": "2026-08-08T12:03:00Z", "event": "Undocked", "StationName": "Abraham Lincoln", "StationType": "Orbis", "MarketID": 128016640 }"#,
+        r#"{ "timestamp": "2026-08-08T12:04:00Z", "event": "SupercruiseEntry", "StarSystem": "Sol", "SystemAddress": 10477373803 }"#,
+        r#"{ "timestamp": "2026-08-08T12:05:00Z", "event": "FSDJump", "StarSystem": "Alpha Centauri", "SystemAddress": 1458376315610, "StarPos": [3.03125, -0.09375, 3.15625], "Body": "Alpha Centauri A", "BodyID": 1, "BodyType": "Star", "JumpDist": 4.378, "FuelUsed": 0.8, "FuelLevel": 15.2 }"#,
+        r#"{ "timestamp": "2026-08-08T12:08:00Z", "event": "SupercruiseExit", "StarSystem": "Alpha Centauri", "SystemAddress": 1458376315610, "Body": "Hutton Orbital", "BodyID": 12, "BodyType": "Station" }"#,
+        r#"{ "timestamp": "2026-08-08T12:09:00Z", "event": "Docked", "StationName": "Hutton Orbital", "StationType": "Outpost", "StarSystem": "Alpha Centauri", "SystemAddress": 1458376315610, "MarketID": 128001536 }"#,
+        r#"{ "timestamp": "2026-08-08T12:10:00Z", "event": "MarketSell", "MarketID": 128001536, "Type": "gold", "Count": 4, "SellPrice": 10000, "TotalSale": 40000, "AvgPricePaid": 9000 }"#,
+    ];
+
+    #[test]
+    fn a_session() {
+        let state = fold::<CommanderState>(SESSION);
+
+        assert_eq!(state.commander.as_deref(), Some("Jameson"));
+        assert_eq!(state.system.as_deref(), Some("Alpha Centauri"));
+        assert_eq!(state.system_address, Some(1458376315610));
+        assert_eq!(state.station.as_deref(), Some("Hutton Orbital"));
+        assert_eq!(state.market_id, Some(128001536));
+        assert_eq!(state.body.as_deref(), Some("Hutton Orbital"));
+        assert!(state.docked);
+        assert_eq!(state.ship.as_ref().map(|s| s.id), Some(1));
+        assert_eq!(state.credits, Some(1000000 - 90000 - 120 + 40000));
+        assert_eq!(state.cargo.get("gold"), Some(&6));
+    }
+
+    /// The same journal twice, and a game loaded partway through it
+    #[test]
+    fn a_journal_always_folds_the_same() {
+        assert_eq!(
+            fold::<CommanderState>(SESSION),
+            fold::<CommanderState>(SESSION)
+        );
+
+        // Loading again forgets where the commander was, until told.
+        let mut lines = SESSION.to_vec();
+        lines.push(SESSION[0]);
+        let reloaded = fold::<CommanderState>(&lines);
+        assert_eq!(reloaded.system, None);
+        assert!(!reloaded.docked);
+        assert!(reloaded.cargo.is_empty());
+        assert_eq!(reloaded.credits, Some(1000000));
+    }
+
+    #[test]
+    fn landing_mining_and_the_hold() {
+        let mut lines = SESSION[..2].to_vec();
+        lines.extend_from_slice(&[
+            r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "Cargo", "Vessel": "Ship", "Count": 3, "Inventory": [ { "Name": "tea", "Count": 2, "Stolen": 0 }, { "Name": "tea", "Count": 1, "Stolen": 0, "MissionID": 880456372 } ] }"#,
+            r#"{ "timestamp": "2026-08-08T12:03:00Z", "event": "Undocked", "StationName": "Abraham Lincoln", "StationType": "Orbis", "MarketID": 128016640 }"#,
+            r#"{ "timestamp": "2026-08-08T12:04:00Z", "event": "MiningRefined", "Type": "$painite_name;", "Type_Localised": "Painite" }"#,
+            r#"{ "timestamp": "2026-08-08T12:04:10Z", "event": "EjectCargo", "Type": "tea", "Count": 3, "Abandoned": true }"#,
+            // Only says how much there is, which leaves the list alone.
+            r#"{ "timestamp": "2026-08-08T12:04:11Z", "event": "Cargo", "Vessel": "Ship", "Count": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:05:00Z", "event": "ApproachBody", "StarSystem": "Sol", "SystemAddress": 10477373803, "Body": "Moon", "BodyID": 4 }"#,
+            r#"{ "timestamp": "2026-08-08T12:06:00Z", "event": "Touchdown", "PlayerControlled": true, "Latitude": 10.5, "Longitude": -20.25, "NearestDestination": "", "StarSystem": "Sol", "SystemAddress": 10477373803, "Body": "Moon", "BodyID": 4, "OnStation": false, "OnPlanet": true }"#,
+        ]);
+        let state = fold::<CommanderState>(&lines);
+
+        assert_eq!(state.station, None);
+        assert_eq!(state.body.as_deref(), Some("Moon"));
+        assert!(state.landed && !state.docked);
+        assert_eq!(
+            state.cargo.iter().collect::<Vec<_>>(),
+            [(&"painite".to_string(), &1)]
+        );
+
+        lines.push(
+            r#"{ "timestamp": "2026-08-08T12:07:00Z", "event": "Liftoff", "PlayerControlled": true, "Taxi": false, "Multicrew": false, "StarSystem": "Sol", "SystemAddress": 10477373803, "Body": "Moon", "BodyID": 4, "OnStation": false, "OnPlanet": true, "Latitude": 10.5, "Longitude": -20.25, "NearestDestination": "" }"#,
+        );
+        let state = fold::<CommanderState>(&lines);
+        assert!(!state.landed);
+        assert_eq!(state.body.as_deref(), Some("Moon"));
+    }
+
+    /// A ship bought is boarded, and known once its `Loadout` is written
+    #[test]
+    fn a_new_ship_waits_for_its_loadout() {
+        let mut lines = SESSION.to_vec();
+        lines.push(
+            r#"{ "timestamp": "2026-08-08T12:11:00Z", "event": "ShipyardBuy", "ShipType": "krait_mkii", "ShipType_Localised": "Krait Mk II", "ShipPrice": 42409425, "StoreOldShip": "CobraMkIII", "StoreShipID": 1, "MarketID": 128001536 }"#,
+        );
+        assert_eq!(fold::<CommanderState>(&lines).ship, None);
+
+        lines.push(
+            r#"{ "timestamp": "2026-08-08T12:11:05Z", "event": "Loadout", "Ship": "krait_mkii", "ShipID": 2, "ShipName": "", "ShipIdent": "", "HullValue": 42000000, "ModulesValue": 0, "HullHealth": 1.0, "UnladenMass": 480.0, "CargoCapacity": 0, "MaxJumpRange": 15.0, "FuelCapacity": { "Main": 32.0, "Reserve": 0.63 }, "Rebuy": 2100000, "Modules": [] }"#,
+        );
+        let state = fold::<CommanderState>(&lines);
+        assert_eq!(state.ship.as_ref().map(|s| s.id), Some(2));
+    }
+
+    /// Everything else that pays or costs, each counted once
+    #[test]
+    fn every_way_credits_move() {
+        let mut lines = SESSION.to_vec();
+        lines.extend_from_slice(&[
+            r#"{ "timestamp": "2026-08-08T12:11:00Z", "event": "MultiSellExplorationData", "Discovered": [ { "SystemName": "Col 285 Sector AA-A b1", "NumBodies": 12 } ], "BaseValue": 200000, "Bonus": 50000, "TotalEarnings": 250000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:12:00Z", "event": "CommunityGoalReward", "CGID": 726, "Name": "Alliance Research Initiative", "System": "Alpha Centauri", "Reward": 500000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:13:00Z", "event": "ModuleSellRemote", "StorageSlot": 3, "SellItem": "$int_hyperdrive_size4_class5_name;", "SellItem_Localised": "FSD", "ServerId": 128064113, "SellPrice": 30000, "Ship": "cobramkiii", "ShipID": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:14:00Z", "event": "CrewHire", "Name": "Margaret Parrish", "CrewID": 236064708, "Faction": "Alpha Centauri Partnership", "Cost": 15000, "CombatRank": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:15:00Z", "event": "NpcCrewPaidWage", "NpcCrewName": "Margaret Parrish", "NpcCrewId": 236064708, "Amount": 2000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:16:00Z", "event": "PayLegacyFines", "Amount": 1000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:17:00Z", "event": "EngineerContribution", "Engineer": "Elvira Martuuk", "EngineerID": 300160, "Type": "Credits", "Quantity": 100000, "TotalQuantity": 100000 }"#,
+            // Handing over cargo costs nothing, and empties the hold of it.
+            r#"{ "timestamp": "2026-08-08T12:17:30Z", "event": "EngineerContribution", "Engineer": "Elvira Martuuk", "EngineerID": 300160, "Type": "Commodity", "Commodity": "gold", "Quantity": 1, "TotalQuantity": 1 }"#,
+            r#"{ "timestamp": "2026-08-08T12:18:00Z", "event": "CollectCargo", "Type": "occupiedcryopod", "Type_Localised": "Occupied Escape Pod", "Stolen": false }"#,
+            r#"{ "timestamp": "2026-08-08T12:19:00Z", "event": "SearchAndRescue", "MarketID": 128001536, "Name": "occupiedcryopod", "Name_Localised": "Occupied Escape Pod", "Count": 1, "Reward": 20000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:20:00Z", "event": "Died", "KillerName": "Cmdr Bob", "KillerShip": "vulture", "KillerRank": "Elite" }"#,
+            r#"{ "timestamp": "2026-08-08T12:21:00Z", "event": "SellShipOnRebuy", "ShipType": "sidewinder", "System": "Sol", "SellShipId": 2, "ShipPrice": 60000 }"#,
+            r#"{ "timestamp": "2026-08-08T12:21:30Z", "event": "Resurrect", "Option": "rebuy", "Cost": 300000, "Bankrupt": false }"#,
+        ]);
+        let died = lines.len() - 3;
+        let before = fold::<CommanderState>(&lines[..died]);
+        assert_eq!(before.cargo["gold"], 5);
+        assert_eq!(before.cargo.get("occupiedcryopod"), None);
+
+        // What was in the hold is lost with the ship.
+        let state = fold::<CommanderState>(&lines);
+        assert!(state.cargo.is_empty());
+
+        let session = 1000000 - 90000 - 120 + 40000;
+        let earned = 250000 + 500000 + 30000 + 20000 + 60000;
+        let spent = 15000 + 2000 + 1000 + 100000 + 300000;
+        assert_eq!(state.credits, Some(session + earned - spent));
+    }
+}
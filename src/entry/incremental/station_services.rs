@@ -0,0 +1,582 @@
+use serde::{Deserialize, Serialize};
+
+/// Fuel bought, the tank filled or only topped up
+///
+/// `RefuelAll` and `RefuelPartial` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Refuel {
+    pub cost: u64,
+    /// Tonnes
+    pub amount: f64,
+}
+
+/// Something on the ship repaired
+///
+/// One thing is written as `Item`, and several as `Items`, which is how the
+/// game has written it since 3.7.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Repair {
+    /// Symbolic, or `Wear`, `Hull`, `Paint` or `All`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<String>,
+    pub cost: u64,
+}
+
+/// The whole ship repaired
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RepairAll {
+    pub cost: u64,
+}
+
+/// An SRV or fighter bought to replace one lost
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RestockVehicle {
+    /// Symbolic, e.g. `testbuggy` or `independent_fighter`
+    #[serde(rename = "Type")]
+    pub vehicle: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_localised: Option<String>,
+    pub loadout: String,
+    /// The pilot who flies a fighter, where it is one
+    #[serde(rename = "ID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>,
+    pub cost: u64,
+    pub count: u64,
+}
+
+/// Ammunition for every weapon restocked
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyAmmo {
+    pub cost: u64,
+}
+
+/// Limpets bought
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyDrones {
+    /// Always `Drones`
+    #[serde(rename = "Type")]
+    pub drone: String,
+    pub count: u64,
+    pub buy_price: u64,
+    pub total_cost: u64,
+}
+
+/// Limpets sold back
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SellDrones {
+    #[serde(rename = "Type")]
+    pub drone: String,
+    pub count: u64,
+    pub sell_price: u64,
+    pub total_sale: u64,
+}
+
+/// A ship bought, with the one flown in stored or sold to pay for it
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardBuy {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    /// Symbolic, e.g. `federation_corvette`
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    pub ship_price: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_old_ship: Option<String>,
+    #[serde(rename = "StoreShipID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_ship_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_old_ship: Option<String>,
+    #[serde(rename = "SellShipID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_ship_id: Option<u64>,
+    /// What the old ship sold for, where it was sold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_price: Option<u64>,
+}
+
+/// A stored ship sold, here or from a shipyard elsewhere
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardSell {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    #[serde(rename = "SellShipID")]
+    pub sell_ship_id: u64,
+    pub ship_price: u64,
+    /// Where the ship was, when it was sold from elsewhere
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    #[serde(rename = "ShipMarketID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_market_id: Option<i64>,
+}
+
+/// A ship sold to help pay for the rebuy of another, from the screen offered
+/// after a death
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SellShipOnRebuy {
+    pub ship_type: String,
+    /// Where the ship was
+    pub system: String,
+    #[serde(rename = "SellShipId")]
+    pub sell_ship_id: u64,
+    pub ship_price: u64,
+}
+
+/// A stored ship taken out, with the one flown in stored or sold
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardSwap {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_old_ship: Option<String>,
+    #[serde(rename = "StoreShipID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub store_ship_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_old_ship: Option<String>,
+    #[serde(rename = "SellShipID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_ship_id: Option<u64>,
+}
+
+/// A stored ship sent for, from a shipyard elsewhere to this one
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipyardTransfer {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    /// Where the ship was
+    pub system: String,
+    #[serde(rename = "ShipMarketID")]
+    pub ship_market_id: i64,
+    /// Light years
+    pub distance: f64,
+    pub transfer_price: u64,
+    /// Seconds until it arrives
+    pub transfer_time: u64,
+}
+
+/// What an engineer did to a stored module, as storage sums it up
+///
+/// Less than a fitted module's
+/// [`Engineering`][crate::entry::incremental::startup::Engineering], which
+/// storage does not keep the modifiers of.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Blueprint {
+    /// e.g. `Weapon_Overcharged`
+    #[serde(rename = "EngineerModifications")]
+    pub name: String,
+    /// The grade, from 1 to 5
+    pub level: u8,
+    /// How far through the grade it has been rolled, from 0 to 1
+    pub quality: f64,
+}
+
+/// A module bought and fitted, with what was in the slot stored or sold
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleBuy {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub slot: String,
+    /// Symbolic, e.g. `$int_shieldgenerator_size3_class5_name;`
+    pub buy_item: String,
+    #[serde(rename = "BuyItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub buy_item_localised: Option<String>,
+    pub buy_price: u64,
+    /// Symbolic, e.g. `cobramkiii`
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stored_item: Option<String>,
+    #[serde(rename = "StoredItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stored_item_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_item: Option<String>,
+    #[serde(rename = "SellItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_item_localised: Option<String>,
+    /// What the module in the slot sold for, where it was sold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_price: Option<u64>,
+}
+
+/// A fitted module sold
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleSell {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub slot: String,
+    pub sell_item: String,
+    #[serde(rename = "SellItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_item_localised: Option<String>,
+    pub sell_price: u64,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+}
+
+/// A stored module sold from wherever it is stored
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleSellRemote {
+    pub storage_slot: u64,
+    pub sell_item: String,
+    #[serde(rename = "SellItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sell_item_localised: Option<String>,
+    #[serde(rename = "ServerId")]
+    pub server_id: u64,
+    pub sell_price: u64,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+}
+
+/// A fitted module taken out and stored, with something put in its place
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleStore {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub slot: String,
+    pub stored_item: String,
+    #[serde(rename = "StoredItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stored_item_localised: Option<String>,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    /// Whether the module is stolen
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hot: bool,
+    #[serde(flatten)]
+    pub blueprint: Option<Blueprint>,
+    /// What was fitted in its place, where anything was
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replacement_item: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<u64>,
+}
+
+/// A stored module taken out and fitted, with what was in the slot stored
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleRetrieve {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub slot: String,
+    pub retrieved_item: String,
+    #[serde(rename = "RetrievedItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retrieved_item_localised: Option<String>,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hot: bool,
+    #[serde(flatten)]
+    pub blueprint: Option<Blueprint>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swap_out_item: Option<String>,
+    #[serde(rename = "SwapOutItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub swap_out_item_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<u64>,
+}
+
+/// Two fitted modules swapped between slots, or one moved to an empty slot
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleSwap {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub from_slot: String,
+    pub to_slot: String,
+    pub from_item: String,
+    #[serde(rename = "FromItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_item_localised: Option<String>,
+    /// `Null` where the slot moved to was empty
+    pub to_item: String,
+    #[serde(rename = "ToItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_item_localised: Option<String>,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+}
+
+/// Several fitted modules stored at once
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MassModuleStore {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    pub items: Vec<MassStoredModule>,
+}
+
+/// One module stored by a [`MassModuleStore`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MassStoredModule {
+    pub slot: String,
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hot: bool,
+    #[serde(flatten)]
+    pub blueprint: Option<Blueprint>,
+}
+
+/// A stored module sent for, from elsewhere to this station
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FetchRemoteModule {
+    pub storage_slot: u64,
+    pub stored_item: String,
+    #[serde(rename = "StoredItem_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stored_item_localised: Option<String>,
+    pub server_id: u64,
+    pub transfer_cost: u64,
+    /// Seconds until it arrives
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_time: Option<u64>,
+    pub ship: String,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+}
+
+/// Every ship the commander owns but is not flying, here and elsewhere
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoredShips {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub station_name: String,
+    pub star_system: String,
+    pub ships_here: Vec<StoredShip>,
+    pub ships_remote: Vec<StoredShip>,
+}
+
+/// One ship in [`StoredShips`]
+///
+/// Where it is, and what sending for it costs, are given only for a ship
+/// stored elsewhere.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoredShip {
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub value: u64,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hot: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub star_system: Option<String>,
+    #[serde(rename = "ShipMarketID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_market_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_price: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_time: Option<u64>,
+    /// Whether it is on its way somewhere already
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub in_transit: bool,
+}
+
+/// Every module in storage, wherever it is stored
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoredModules {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub station_name: String,
+    pub star_system: String,
+    pub items: Vec<StoredModule>,
+}
+
+/// One module in [`StoredModules`]
+///
+/// Where it is, and what sending for it costs, are left out while it is on
+/// its way somewhere already.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct StoredModule {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub storage_slot: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub star_system: Option<String>,
+    #[serde(rename = "MarketID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_cost: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_time: Option<u64>,
+    /// What it cost when bought
+    pub buy_price: u64,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hot: bool,
+    #[serde(flatten)]
+    pub blueprint: Option<Blueprint>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub in_transit: bool,
+}
+
+/// Fines or bounties on the commander paid off
+///
+/// `PayFines` and `PayBounties` are written the same way. A broker takes a
+/// cut for paying off another jurisdiction's, and says how much.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayFines {
+    pub amount: u64,
+    /// Whether that was all of them, where the game says
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all_fines: Option<bool>,
+    /// The faction paid, where only one was
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+    #[serde(rename = "Faction_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction_localised: Option<String>,
+    #[serde(rename = "ShipID")]
+    pub ship_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broker_percentage: Option<f64>,
+}
+
+/// Fines run up before 3.0 paid off, which they cannot be one at a time
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PayLegacyFines {
+    pub amount: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broker_percentage: Option<f64>,
+}
+
+/// Vouchers cashed in
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RedeemVoucher {
+    #[serde(rename = "Type")]
+    pub voucher: Voucher,
+    pub amount: u64,
+    /// The faction paying, where only one is
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub faction: Option<String>,
+    /// What each faction paid, for bounties owed by several
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub factions: Vec<VoucherFaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub broker_percentage: Option<f64>,
+}
+
+/// The kind of [`RedeemVoucher`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum Voucher {
+    #[serde(rename = "bounty")]
+    Bounty,
+    CombatBond,
+    #[serde(rename = "trade")]
+    Trade,
+    #[serde(rename = "settlement")]
+    Settlement,
+    #[serde(rename = "scannable")]
+    Scannable,
+    #[serde(rename = "codex")]
+    Codex,
+    /// A kind added since, by its name
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// One faction's part of a [`RedeemVoucher`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct VoucherFaction {
+    /// Empty for the bounties the Pilots Federation pays
+    pub faction: String,
+    pub amount: u64,
+}
+
+/// A pilot taken on at the crew lounge, for a fee up front and a cut of
+/// earnings after
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CrewHire {
+    pub name: String,
+    #[serde(rename = "CrewID")]
+    pub crew_id: u64,
+    pub faction: String,
+    pub cost: u64,
+    /// From 0, for Harmless
+    pub combat_rank: u8,
+}
+
+/// A hired pilot's cut of what the commander earned
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct NpcCrewPaidWage {
+    pub npc_crew_name: String,
+    #[serde(rename = "NpcCrewId")]
+    pub npc_crew_id: u64,
+    pub amount: u64,
+}
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+
+/// A commodity bought on a station's market
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MarketBuy {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    /// The game's own name for it, lowercase and unspaced
+    #[serde(rename = "Type")]
+    pub commodity: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    pub count: u64,
+    pub buy_price: u64,
+    pub total_cost: u64,
+}
+
+/// A commodity sold on a station's market, black or not
+///
+/// Gives what was paid for it on average as well as what it sold for, which is
+/// enough to know the profit without having watched it bought.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MarketSell {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    #[serde(rename = "Type")]
+    pub commodity: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    pub count: u64,
+    pub sell_price: u64,
+    pub total_sale: u64,
+    /// Per tonne, and `0` for cargo that cost nothing, mined or scooped
+    pub avg_price_paid: u64,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub illegal_goods: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stolen_goods: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub black_market: bool,
+}
+
+impl MarketSell {
+    /// Credits made on the sale, over what the cargo cost
+    ///
+    /// Held at the ends of an `i64` where a sum the game wrote goes past them.
+    pub fn profit(&self) -> i64 {
+        let paid = self.avg_price_paid as i128 * self.count as i128;
+        let profit = self.total_sale as i128 - paid;
+        profit.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+}
+
+/// Escape pods, black boxes and the like handed in at a station, for a reward
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SearchAndRescue {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    /// Symbolic, e.g. `occupiedcryopod`
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    pub count: u64,
+    pub reward: u64,
+}
+
+/// A system's trade data bought
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BuyTradeData {
+    pub system: String,
+    pub cost: u64,
+}
+
+/// A canister scooped up
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CollectCargo {
+    #[serde(rename = "Type")]
+    pub commodity: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    pub stolen: bool,
+    #[serde(rename = "MissionID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<u64>,
+}
+
+/// Cargo jettisoned, or left behind with a mission abandoned
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EjectCargo {
+    #[serde(rename = "Type")]
+    pub commodity: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    pub count: u64,
+    pub abandoned: bool,
+    #[serde(rename = "MissionID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<u64>,
+    /// The system Powerplay goods came from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub powerplay_origin: Option<String>,
+}
+
+/// Cargo moved between the ship and the SRV or a carrier
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CargoTransfer {
+    pub transfers: Vec<Transfer>,
+}
+
+/// One commodity moved in a [`CargoTransfer`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Transfer {
+    #[serde(rename = "Type")]
+    pub commodity: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+    pub count: u64,
+    pub direction: TransferDirection,
+}
+
+/// Where a [`Transfer`] went
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    #[serde(rename = "toship")]
+    ToShip,
+    #[serde(rename = "tosrv")]
+    ToSrv,
+    #[serde(rename = "tocarrier")]
+    ToCarrier,
+}
+
+/// Cargo collected or delivered for a wing mission, or word of the wing's
+///
+/// Names the market the cargo is collected from and the one it goes to, and
+/// how far along the whole delivery is.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CargoDepot {
+    #[serde(rename = "MissionID")]
+    pub mission_id: u64,
+    pub update_type: CargoDepotUpdate,
+    /// Left out of a [`CargoDepotUpdate::WingUpdate`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cargo_type: Option<String>,
+    #[serde(rename = "CargoType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cargo_type_localised: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    #[serde(rename = "StartMarketID")]
+    pub start_market_id: i64,
+    #[serde(rename = "EndMarketID")]
+    pub end_market_id: i64,
+    pub items_collected: u64,
+    pub items_delivered: u64,
+    pub total_items_to_deliver: u64,
+    /// From 0 to 1
+    pub progress: f64,
+}
+
+/// What a [`CargoDepot`] is about
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CargoDepotUpdate {
+    Collect,
+    Deliver,
+    /// Somebody else in the wing collected or delivered
+    WingUpdate,
+}
+
+/// A tonne refined from what was mined
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MiningRefined {
+    /// Symbolic, e.g. `$painite_name;`
+    #[serde(rename = "Type")]
+    pub commodity: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commodity_localised: Option<String>,
+}
+
+/// An asteroid looked at with a prospector limpet
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProspectedAsteroid {
+    pub materials: Vec<AsteroidMaterial>,
+    /// Symbolic, e.g. `$AsteroidMaterialContent_High;`
+    pub content: String,
+    #[serde(rename = "Content_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_localised: Option<String>,
+    /// What a core asteroid's core is, where it is one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub motherlode_material: Option<String>,
+    #[serde(rename = "MotherlodeMaterial_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub motherlode_material_localised: Option<String>,
+    /// Percent, from 0 to 100, of it still to be mined
+    pub remaining: f64,
+}
+
+/// One commodity in a [`ProspectedAsteroid`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AsteroidMaterial {
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    /// Percent, from 0 to 100, of the asteroid
+    pub proportion: f64,
+}
+
+/// A core asteroid broken open with charges
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AsteroidCracked {
+    /// The ring it was in
+    pub body: String,
+}
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Coming up on a settlement, which is a station on a planet's surface
 ///
@@ -10,40 +10,51 @@ use serde::Deserialize;
 /// Names the settlement `Name` rather than `StationName`, which is why the
 /// station cannot simply be flattened in the way [`Docked`] and [`Location`]
 /// flatten theirs.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct ApproachSettlement {
     pub name: String,
     #[serde(rename = "MarketID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub market_id: Option<i64>,
 
     /// [`None`] as the game writes it, which names only the address
     #[serde(rename = "StarSystem")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub system_name: Option<String>,
+    #[serde(serialize_with = "crate::system::optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub star_pos: Option<Coordinate>,
     pub system_address: i64,
 
     #[serde(rename = "BodyID")]
     pub body_id: i16,
     pub body_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub longitude: Option<f64>,
 
     #[serde(rename = "StationFaction")]
     #[serde(default)]
     #[serde(deserialize_with = "crate::de::empty_map_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub faction: Option<Faction>,
     #[serde(rename = "StationGovernment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub government: Option<Government>,
     #[serde(rename = "StationAllegiance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allegiance: Option<Allegiance>,
     #[serde(rename = "StationServices")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub services: Option<Vec<Service>>,
     #[serde(rename = "StationEconomies")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub economies: Option<Vec<EconomyShare>>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct ApproachBody {
     // TODO: Unify names in simply syntax/format.
@@ -59,10 +70,11 @@ pub struct ApproachBody {
 /// and is never sent. Every docking event below named it that way and so
 /// read none of the messages it was written for; [`Docked`] escaped only
 /// because it flattens a [`Station`], where the name is spelled out by hand.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct DockingRequested {
     pub station_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub station_type: Option<String>,
     #[serde(rename = "MarketID")]
     pub market_id: u64,
@@ -72,53 +84,60 @@ pub struct DockingRequested {
     /// the game has never sent it: the field is `{"Small":19,"Medium":21,
     /// "Large":9}`, which is what [`LandingPads`] is. Optional because the
     /// game did not always send it at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub landing_pads: Option<LandingPads>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct DockingGranted {
     pub station_name: String,
     /// Optional in the schema, and sent by most but not all
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub station_type: Option<String>,
     #[serde(rename = "MarketID")]
     pub market_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub landing_pad: Option<u8>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct DockingDenied {
     pub station_name: String,
     /// Optional in the schema, and sent by most but not all
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub station_type: Option<String>,
     #[serde(rename = "MarketID")]
     pub market_id: u64,
     pub reason: DockingDeniedReason,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct DockingCancelled {
     pub station_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub station_type: Option<String>,
     #[serde(rename = "MarketID")]
     pub market_id: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct DockingTimeout {
     pub station_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub station_type: Option<String>,
     #[serde(rename = "MarketID")]
     pub market_id: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Docked {
     // NOTE: Should really be Some(false) when parsed locally. EDDN filters this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub active_fine: Option<bool>,
     pub system_address: i64,
     #[serde(rename = "StarSystem")]
@@ -127,7 +146,7 @@ pub struct Docked {
     pub station: Station,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Undocked {
     pub station_name: String,
@@ -135,60 +154,120 @@ pub struct Undocked {
     pub market_id: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct FsdTarget {
     pub system_address: i64,
     pub name: String,
     pub star_class: String, // TODO: Enum?
     #[serde(rename = "RemainingJumpsInRoute")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remaining: Option<u16>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum FsdJumpType {
     Hyperspace,
     Supercruise,
 }
 
-#[derive(Deserialize, Debug)]
+/// The frame shift drive charged, for a hyperspace jump or for supercruise
+///
+/// Only a hyperspace jump says where it is going.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct StartJump {
     #[serde(rename = "JumpType")]
     pub ty: FsdJumpType,
-    pub system_address: String,
+    /// Aboard an Apex taxi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_address: Option<i64>,
     #[serde(rename = "StarSystem")]
-    pub system_name: String,
-    pub star_class: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub star_class: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+/// Into supercruise
+///
+/// The system's address is left out of journals older than 3.5.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct SupercruiseEntry {
     #[serde(rename = "StarSystem")]
     pub system_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_address: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multicrew: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+/// Out of supercruise, near the body named
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct SupercruiseExit {
     #[serde(rename = "StarSystem")]
     pub system_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_address: Option<i64>,
+    #[serde(rename = "BodyID")]
     pub body_id: i16,
     pub body: String,
-    pub body_type: String,
+    pub body_type: BodyType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multicrew: Option<bool>,
+}
+
+/// Dropped out of supercruise at a signal source or station, written just
+/// before the [`SupercruiseExit`]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SupercruiseDestinationDrop {
+    /// Symbolic for a signal source, e.g. `$USS_Type_Salvage;`, and the
+    /// station's name for a station
+    #[serde(rename = "Type")]
+    pub destination: String,
+    #[serde(rename = "Type_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_localised: Option<String>,
+    /// How dangerous it is, from 0
+    pub threat: u8,
+    #[serde(rename = "MarketID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market_id: Option<i64>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct FsdJump {
     #[serde(flatten)]
     pub system: System,
     #[serde(flatten)]
     pub cost: JumpCost,
+    /// Aboard an Apex taxi
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+    /// Aboard someone else's ship
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multicrew: Option<bool>,
+    /// The body arrived at, which is the main star
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(rename = "BodyID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_id: Option<i16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_type: Option<BodyType>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct LeaveBody {
     // TODO: Unify names in simply syntax/format.
@@ -201,47 +280,143 @@ pub struct LeaveBody {
     pub body_id: i16,
 }
 
-#[derive(Deserialize, Debug)]
+/// Off a planet, or taken up by a taxi or dropship
+///
+/// Where it was on the planet is only given for a takeoff the commander flew.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Liftoff {
+    pub system_address: i64,
     #[serde(rename = "StarSystem")]
     pub system_name: String,
     #[serde(rename = "Body")]
     pub body_name: String,
+    #[serde(rename = "BodyID")]
     pub body_id: i16,
-    pub latitude: f64,
-    pub longitude: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
     pub on_station: bool,
     pub on_planet: bool,
-    pub nearest_destination: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nearest_destination: Option<String>,
+    #[serde(rename = "NearestDestination_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nearest_destination_localised: Option<String>,
     pub player_controlled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multicrew: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+/// Landed on a planet, or set down by a taxi or dropship
+///
+/// Where it is on the planet is only given for a landing the commander flew.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Touchdown {
     pub system_address: i64,
     #[serde(rename = "StarSystem")]
     pub system_name: String,
+    #[serde(rename = "Body")]
     pub body_name: String,
     #[serde(rename = "BodyID")]
     pub body_id: i16,
-    pub latitude: f64,
-    pub longitude: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
     pub on_station: bool,
     pub on_planet: bool,
-    pub nearest_destination: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nearest_destination: Option<String>,
+    #[serde(rename = "NearestDestination_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nearest_destination_localised: Option<String>,
     pub player_controlled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taxi: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multicrew: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+/// Fuel scooped from a star, written every five tonnes and when it stops
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FuelScoop {
+    /// Tonnes taken on since the last
+    pub scooped: f64,
+    /// Tonnes in the main tank afterwards
+    pub total: f64,
+}
+
+/// A supercharge from a neutron star or white dwarf's cone
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct JetConeBoost {
+    /// What the jump range is multiplied by
+    pub boost_value: f64,
+}
+
+/// The reservoir that feeds the power plant topped up from the main tank
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReservoirReplenished {
+    /// Tonnes left in the main tank
+    pub fuel_main: f64,
+    pub fuel_reservoir: f64,
+}
+
+/// An SRV deployed from the ship
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct LaunchSrv {
+    /// Left out before there was more than one, e.g. `testbuggy`
+    #[serde(rename = "SRVType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srv_type: Option<String>,
+    #[serde(rename = "SRVType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srv_type_localised: Option<String>,
+    /// e.g. `starter`
+    pub loadout: String,
+    #[serde(rename = "ID")]
+    pub id: u64,
+    pub player_controlled: bool,
+}
+
+/// An SRV back aboard, or lost
+///
+/// `DockSRV` and `SRVDestroyed` are written the same way.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Srv {
+    #[serde(rename = "SRVType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srv_type: Option<String>,
+    #[serde(rename = "SRVType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srv_type_localised: Option<String>,
+    #[serde(rename = "ID")]
+    pub id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Location {
     #[serde(flatten)]
     pub system: System,
     #[serde(flatten)]
     pub body: Option<Body>,
+    /// The body nearest, by name, which is all the game says of it here
+    #[serde(rename = "Body")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub longitude: Option<f64>,
     pub docked: bool,
     #[serde(flatten)]
@@ -259,13 +434,18 @@ pub struct Location {
 /// Told apart from [`Location`] only by `docked`, which the game began sending
 /// partway through the event's life and which nothing here needs. A carrier
 /// jump that predates it is still a carrier jump.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct CarrierJump {
     #[serde(flatten)]
     pub system: System,
     #[serde(flatten)]
     pub body: Option<Body>,
+    /// The body nearest, by name, which is all the game says of it here
+    #[serde(rename = "Body")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub docked: Option<bool>,
     #[serde(flatten)]
     pub station: Option<Station>,
@@ -0,0 +1,92 @@
+//! What a commander carries on foot, and what waits for them aboard
+//!
+//! Odyssey's goods, components and data are kept in two places: the backpack
+//! worn on foot, and the ship's locker, which holds the rest. The game writes
+//! each to a file beside the journal, `Backpack.json` and `ShipLocker.json`,
+//! both read as an [`Entry`][crate::entry::Entry] of a [`Locker`]. The
+//! `Backpack` and `ShipLocker` journal events are the same again, and have
+//! been written with the lists left out, pointing at the files.
+
+use serde::{Deserialize, Serialize};
+
+/// `Backpack.json` or `ShipLocker.json`, which hold the same four lists
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Locker {
+    /// Goods, e.g. `chemicalsample`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<MicroResource>,
+    /// Crafting components, e.g. `graphene`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<MicroResource>,
+    /// Used up as they are used, e.g. `healthpack`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumables: Vec<MicroResource>,
+    /// Downloaded from terminals, e.g. `employeedirectory`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub data: Vec<MicroResource>,
+}
+
+/// One line of a [`Locker`]
+///
+/// As with cargo, something carried for a mission is a line of its own, so a
+/// name can appear more than once.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MicroResource {
+    /// The game's own name for it, lowercase and unspaced
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    /// Who it belongs to, which is `0` where it is the commander's own
+    #[serde(rename = "OwnerID")]
+    pub owner_id: u64,
+    /// The mission it is carried for, where it is
+    #[serde(rename = "MissionID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mission_id: Option<u64>,
+    pub count: u64,
+}
+
+/// `Backpack.json` and `ShipLocker.json` as the game writes them
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::read_status_file;
+    use crate::entry::Entry;
+
+    #[test]
+    fn ship_locker_json_reads_through_parse_status_file() {
+        let locker = read_status_file::<Locker>(
+            "ShipLocker.json",
+            r#"{ "timestamp":"2026-08-08T12:00:00Z", "event":"ShipLocker", "Items":[
+{ "Name":"chemicalsample", "Name_Localised":"Chemical Sample", "OwnerID":0, "Count":2 },
+{ "Name":"insightdatabank", "Name_Localised":"Insight Data Bank", "OwnerID":35813, "MissionID":880456372, "Count":1 }
+ ], "Components":[
+{ "Name":"graphene", "OwnerID":0, "Count":7 }
+ ], "Consumables":[
+{ "Name":"healthpack", "Name_Localised":"Medkit", "OwnerID":0, "Count":4 }
+ ], "Data":[
+{ "Name":"employeedirectory", "Name_Localised":"Employee Directory", "OwnerID":0, "Count":1 }
+ ] }"#,
+        )
+        .event;
+        assert_eq!(locker.items[1].owner_id, 35813);
+        assert_eq!(locker.items[1].mission_id, Some(880456372));
+        assert_eq!(locker.components[0].name_localised, None);
+        assert_eq!(locker.consumables[0].count, 4);
+        assert_eq!(locker.data[0].name, "employeedirectory");
+    }
+
+    /// The journal event, which lately leaves the lists to the file
+    #[test]
+    fn a_backpack_event_without_its_lists() {
+        let entry: Entry<Locker> = serde_json::from_str(
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "Backpack" }"#,
+        )
+        .expect("the Backpack event should read");
+
+        assert_eq!(entry.event, Locker::default());
+    }
+}
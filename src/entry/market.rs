@@ -144,6 +144,7 @@ pub struct Shipyard {
     /// A property of the commander rather than of the shipyard, and the
     /// reason a Cobra MkIV missing from `ships` says nothing.
     #[serde(rename = "allowCobraMkIV")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_cobra_mk_iv: Option<bool>,
 }
 
@@ -160,6 +161,7 @@ pub struct BlackMarket {
     pub station_name: String,
     /// Not required by the schema, though a sale cannot be placed without it
     #[serde(rename = "marketId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub market_id: Option<i64>,
 
     pub name: String,
@@ -168,7 +170,99 @@ pub struct BlackMarket {
     pub prohibited: bool,
 }
 
-/// The market schemas, read from the shape EDDN sends
+/// `Market.json`, a station's commodity market as the game writes it
+///
+/// What EDDN's [`Market`] is made from, with more in it: each commodity's
+/// category, what the commander's game calls it, and whether the station makes
+/// it or wants it. Read as an [`Entry`][crate::entry::Entry] of this, and
+/// turned into a [`Market`] with [`From`].
+///
+/// The `Market` journal event is this without the items, which go in the file.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct JournalMarket {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub station_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub station_type: Option<String>,
+    pub star_system: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<MarketItem>,
+}
+
+/// One commodity in a [`JournalMarket`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MarketItem {
+    #[serde(rename = "id")]
+    pub id: i64,
+    /// Symbolic, e.g. `$explosives_name;`, where EDDN sends `explosives`
+    pub name: String,
+    #[serde(rename = "Name_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localised: Option<String>,
+    /// Symbolic, e.g. `$MARKET_category_chemicals;`
+    pub category: String,
+    #[serde(rename = "Category_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_localised: Option<String>,
+    pub buy_price: i32,
+    pub sell_price: i32,
+    pub mean_price: i32,
+    #[serde(deserialize_with = "bracket")]
+    pub stock_bracket: i32,
+    #[serde(deserialize_with = "bracket")]
+    pub demand_bracket: i32,
+    pub stock: i32,
+    pub demand: i32,
+    /// Whether the station's economy wants it
+    #[serde(default)]
+    pub consumer: bool,
+    /// Whether the station's economy makes it
+    #[serde(default)]
+    pub producer: bool,
+    #[serde(default)]
+    pub rare: bool,
+}
+
+impl From<JournalMarket> for Market {
+    /// The market as EDDN has it, which names each commodity plainly
+    fn from(market: JournalMarket) -> Self {
+        Market {
+            system_name: market.star_system,
+            station_name: market.station_name,
+            market_id: market.market_id,
+            commodities: market
+                .items
+                .into_iter()
+                .map(|item| Commodity {
+                    name: plain_name(&item.name),
+                    mean_price: item.mean_price,
+                    buy_price: item.buy_price,
+                    sell_price: item.sell_price,
+                    demand: item.demand,
+                    demand_bracket: item.demand_bracket,
+                    stock: item.stock,
+                    stock_bracket: item.stock_bracket,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// `$explosives_name;` as EDDN spells it, `explosives`
+///
+/// A name that is not wrapped that way is already plain, and is only
+/// lowercased.
+fn plain_name(name: &str) -> String {
+    name.strip_prefix('$')
+        .and_then(|name| name.strip_suffix("_name;"))
+        .unwrap_or(name)
+        .to_lowercase()
+}
+
+/// The market schemas, read from the shape EDDN sends and from the game's files
 ///
 /// None of these carries an `event`, so nothing about the payload says what it
 /// is; the `$schemaRef` above it is the only thing that does. They are written
@@ -395,4 +489,32 @@ mod tests {
             err,
         );
     }
+
+    /// `Market.json`, which is where a commander docked reads it from
+    #[test]
+    fn the_games_own_market_file() {
+        let entry: Entry<JournalMarket> = serde_json::from_str(
+            r#"{ "timestamp":"2026-08-08T12:00:00Z", "event":"Market", "MarketID":128016384, "StationName":"Abraham Lincoln", "StationType":"Orbis", "StarSystem":"Sol", "Items":[
+{ "id":128049204, "Name":"$explosives_name;", "Name_Localised":"Explosives", "Category":"$MARKET_category_chemicals;", "Category_Localised":"Chemicals", "BuyPrice":0, "SellPrice":277, "MeanPrice":328, "StockBracket":0, "DemandBracket":2, "Stock":0, "Demand":3066, "Consumer":true, "Producer":false, "Rare":false },
+{ "id":128049202, "Name":"$hydrogenfuel_name;", "Name_Localised":"Hydrogen Fuel", "Category":"$MARKET_category_chemicals;", "Category_Localised":"Chemicals", "BuyPrice":79, "SellPrice":75, "MeanPrice":110, "StockBracket":3, "DemandBracket":0, "Stock":134656, "Demand":1, "Consumer":false, "Producer":true, "Rare":false }
+ ] }"#,
+        )
+        .expect("Market.json should parse");
+
+        let market = entry.event;
+        assert_eq!(market.star_system, "Sol");
+        assert_eq!(
+            market.items[0].category_localised.as_deref(),
+            Some("Chemicals")
+        );
+        assert!(market.items[0].consumer);
+        assert!(market.items[1].producer);
+
+        let market = Market::from(market);
+        assert_eq!(market.system_name, "Sol");
+        assert_eq!(market.market_id, 128016384);
+        assert_eq!(market.commodities[0].name, "explosives");
+        assert_eq!(market.commodities[1].name, "hydrogenfuel");
+        assert_eq!(market.commodities[1].stock, 134656);
+    }
 }
@@ -1,13 +1,15 @@
+use crate::Error;
 use chrono::prelude::*;
-use serde::Deserialize;
-use std::error::Error;
-use std::ffi::OsStr;
-use std::fs::{read_dir, File};
-use std::io::{BufRead, BufReader};
+use serde::{Deserialize, Serialize};
+use std::fs::read_to_string;
 use std::path::Path;
 
 /// A single timestamped entry, containing an [`Event`], [`NavRoute`], etc.
-#[derive(Deserialize, Debug, PartialEq)]
+///
+/// Written back out the way the game writes it, one to a line, which makes
+/// [`serde_json::to_string`] of one a journal line. The two flags are left out
+/// where they are false, as the game leaves them out.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Entry<E> {
     pub timestamp: DateTime<Utc>,
     #[serde(flatten)]
@@ -21,8 +23,10 @@ pub struct Entry<E> {
     // pub star_pos: Coordinate,
     // pub system_address: i64,
     #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub horizons: bool,
     #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub odyssey: bool,
 }
 
@@ -76,51 +80,41 @@ fn entry() {
 }
 
 /// Parse a single file's worth of journal entries
-// TODO: Our own error types.
-// TODO: add result inside vec too.
-// TODO: This should be an interator, since files are updates as the game runs.
+///
+/// One result a line, so that whatever did not read is still there to be
+/// counted. See [`JournalReader`] to read them as they come instead, and
+/// [`JournalTail`] to follow the one the game is still writing.
 pub fn parse_journal_file<P: AsRef<Path>>(
     path: P,
-) -> Result<Vec<Entry<Event>>, Box<dyn Error>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    Ok(reader
-        .lines()
-        .filter_map(|line| line.map(|l| serde_json::from_str(&l)).ok())
-        .flatten()
-        .collect())
+) -> Result<Vec<Result<Entry<Event>, LineError>>, Error> {
+    Ok(JournalReader::open(path)?.collect())
 }
 
 /// Parse all journals file's entries in a directory
-// TODO: Our own error types.
-// TODO: add result inside vec too.
-// TODO: is there a way to stream this too? search for current running log files?
+///
+/// In the order the game wrote them, which is the order of the names and not
+/// of the listing. See [`parse_journal_sessions`] to have them a game session
+/// at a time, and `stream::parse_journal_dir` to read them on an async
+/// runtime with the `with-async-std` feature.
 pub fn parse_journal_dir<P: AsRef<Path>>(
     path: P,
-) -> Result<Vec<Entry<Event>>, Box<dyn Error>> {
+) -> Result<Vec<Result<Entry<Event>, LineError>>, Error> {
     let mut entries = Vec::new();
-    for entry in read_dir(path)? {
-        let entry = entry?;
-        if entry.file_type().unwrap().is_file()
-            && entry.path().extension().and_then(OsStr::to_str) == Some("log")
-        {
-            entries.append(&mut parse_journal_file(entry.path())?);
-        }
+    for file in journal_files(path)? {
+        entries.extend(file.read()?);
     }
     Ok(entries)
 }
 
 /// Parse a status file entry
-// TODO: Our own error types.
-pub fn parse_status_file<P: AsRef<Path>, E>(
-    path: P,
-) -> Result<Entry<E>, serde_json::Error>
+pub fn parse_status_file<P: AsRef<Path>, E>(path: P) -> Result<Entry<E>, Error>
 where
     for<'de> E: Deserialize<'de>,
 {
-    let file = File::open(path).unwrap();
-    let reader = BufReader::new(file);
-    serde_json::from_reader(reader)
+    let path = path.as_ref();
+    let text = read_to_string(path).map_err(|e| Error::io(path, None, e))?;
+    serde_json::from_str(&text)
+        .map_err(|e| Error::from_json::<E, _>(path, None, &text, e))
 }
 
 /// `Journal.<timestamp>.<part>.log`
@@ -130,30 +124,64 @@ where
 ///
 /// Each incremental journal file will begin with a [`Fileheader`][incremental::Event::Fileheader]
 /// event which in addition to some other metadata, also contains the `part` of the log. This, in
-/// addition to the ubiquitous `timestamp` makes parsing the filename unnecessary. For more
-/// information on each [`Event`] read their individual documentation.
+/// addition to the ubiquitous `timestamp` makes parsing the filename unnecessary once a file is
+/// open. Putting a directory of them in order before opening any is what the filename is for,
+/// see [`dir`]. For more information on each [`Event`] read their individual documentation.
 pub mod incremental;
 pub use self::incremental::Event;
 
-/// `Status.json` TODO
-pub mod status {}
+/// Journals read a line at a time
+pub mod reader;
+pub use self::reader::{JournalReader, LineError};
+
+/// A directory of journals, put in the order they were written
+pub mod dir;
+pub use self::dir::{
+    journal_files, parse_journal_sessions, JournalFile, Session,
+};
+
+/// The journal the game is writing, read as it is written
+pub mod tail;
+pub use self::tail::JournalTail;
+
+#[cfg(feature = "with-async-std")]
+pub mod stream;
+
+/// `Status.json`
+pub mod status;
+pub use self::status::Status;
 
 /// `NavRoute.json`
 pub mod route;
 pub use self::route::NavRoute;
 
-/// `Cargo.json` TODO
-pub mod cargo {}
+/// `Cargo.json`
+pub mod cargo;
+pub use self::cargo::Manifest;
 
-/// `Market.json` TODO
+/// `Market.json`, and the market schemas EDDN sends
 pub mod market;
-pub use self::market::Market;
+pub use self::market::{JournalMarket, Market};
+
+/// `Shipyard.json`
+pub mod shipyard;
+pub use self::shipyard::JournalShipyard;
+
+/// `Outfitting.json`
+pub mod outfitting;
+pub use self::outfitting::JournalOutfitting;
+
+/// `ModulesInfo.json`
+pub mod modules_info;
+pub use self::modules_info::ModulesInfo;
 
-/// `Shipyard.json` TODO
-pub mod shipyard {}
+/// `Backpack.json` and `ShipLocker.json`
+pub mod locker;
+pub use self::locker::Locker;
 
-/// `Outfitting.json` TODO
-pub mod outfitting {}
+/// `FCMaterials.json`
+pub mod fc_materials;
+pub use self::fc_materials::FcMaterials;
 
-/// `ModulesInfo.json` TODO
-pub mod modules_info {}
+#[cfg(test)]
+pub(crate) mod testing;
@@ -0,0 +1,60 @@
+//! What each module fitted to the ship draws from the power plant
+//!
+//! The `ModuleInfo` journal event says the modules panel was looked at, and
+//! the modules go in `ModulesInfo.json`, which is read as an
+//! [`Entry`][crate::entry::Entry] of the same [`ModulesInfo`]. What the
+//! modules are and how they were engineered is in the
+//! [`Loadout`][crate::entry::incremental::startup::Loadout] event.
+
+use serde::{Deserialize, Serialize};
+
+/// `ModulesInfo.json`, or the `ModuleInfo` event without its modules
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModulesInfo {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modules: Vec<ModuleInfo>,
+}
+
+/// One module in a [`ModulesInfo`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModuleInfo {
+    /// Where it is fitted, e.g. `MainEngines`
+    pub slot: String,
+    /// Symbolic and lowercase, e.g. `int_engine_size2_class2`
+    pub item: String,
+    /// Megawatts
+    pub power: f64,
+    /// The power group it is shed in, from 0, which is shed last
+    pub priority: u8,
+}
+
+impl ModulesInfo {
+    /// Megawatts drawn by everything, whatever its priority
+    pub fn power(&self) -> f64 {
+        self.modules.iter().map(|module| module.power).sum()
+    }
+}
+
+/// `ModulesInfo.json` as the game writes it
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::read_status_file;
+
+    #[test]
+    fn modules_info_json_reads_through_parse_status_file() {
+        let info = read_status_file::<ModulesInfo>(
+            "ModulesInfo.json",
+            r#"{ "timestamp":"2026-08-08T12:00:00Z", "event":"ModuleInfo", "Modules":[
+{ "Slot":"MainEngines", "Item":"int_engine_size2_class2", "Power":2.04, "Priority":0 },
+{ "Slot":"MediumHardpoint1", "Item":"hpt_pulselaser_gimbal_medium", "Power":0.6, "Priority":2 }
+ ] }"#,
+        )
+        .event;
+        assert_eq!(info.modules[1].slot, "MediumHardpoint1");
+        assert_eq!(info.modules[1].priority, 2);
+        assert!((info.power() - 2.64).abs() < 1e-9);
+    }
+}
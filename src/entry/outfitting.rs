@@ -0,0 +1,79 @@
+//! A station's outfitting bay as the game writes it beside the journal
+//!
+//! The `Outfitting` journal event says the bay was opened, and the modules on
+//! sale go in `Outfitting.json`, which is read as an
+//! [`Entry`][crate::entry::Entry] of the same [`JournalOutfitting`].
+
+use crate::entry::market::{Module, Outfitting};
+use serde::{Deserialize, Serialize};
+
+/// `Outfitting.json`, or the `Outfitting` event without its items
+///
+/// Turned into EDDN's [`Outfitting`] with [`From`], as `outfitting/2` names
+/// alone. The file gives no price in merits, and a [`Module::Priced`] made
+/// from it would have to make one up.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct JournalOutfitting {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub station_name: String,
+    pub star_system: String,
+    /// Whether the commander had Horizons, which some modules need
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizons: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub items: Vec<OutfittingItem>,
+}
+
+/// One module for sale in a [`JournalOutfitting`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct OutfittingItem {
+    #[serde(rename = "id")]
+    pub id: i64,
+    /// Symbolic and lowercase, e.g. `int_engine_size3_class5_fast`
+    pub name: String,
+    pub buy_price: i64,
+}
+
+impl From<JournalOutfitting> for Outfitting {
+    fn from(outfitting: JournalOutfitting) -> Self {
+        Outfitting {
+            system_name: outfitting.star_system,
+            station_name: outfitting.station_name,
+            market_id: outfitting.market_id,
+            modules: outfitting
+                .items
+                .into_iter()
+                .map(|item| Module::Named(item.name))
+                .collect(),
+        }
+    }
+}
+
+/// `Outfitting.json` as the game writes it
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::read_status_file;
+
+    #[test]
+    fn outfitting_json_reads_through_parse_status_file() {
+        let outfitting = read_status_file::<JournalOutfitting>(
+            "Outfitting.json",
+            r#"{ "timestamp":"2026-08-08T12:00:00Z", "event":"Outfitting", "MarketID":128016384, "StationName":"Abraham Lincoln", "StarSystem":"Sol", "Horizons":true, "Items":[
+{ "id":128064258, "Name":"int_engine_size3_class5_fast", "BuyPrice":5103953 },
+{ "id":128049250, "Name":"hpt_pulselaser_fixed_small", "BuyPrice":2200 }
+ ] }"#,
+        )
+        .event;
+        assert_eq!(outfitting.items.len(), 2);
+        assert_eq!(outfitting.items[0].buy_price, 5103953);
+
+        let outfitting = Outfitting::from(outfitting);
+        assert_eq!(outfitting.market_id, 128016384);
+        assert_eq!(outfitting.modules[1].name(), "hpt_pulselaser_fixed_small");
+        assert_eq!(outfitting.modules[1].buy_price(), None);
+    }
+}
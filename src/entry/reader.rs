@@ -0,0 +1,291 @@
+use crate::entry::{Entry, Event};
+use crate::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+/// A journal read a line at a time, with every line accounted for
+///
+/// Each line is an entry or the reason it is not one. A line that does not
+/// read is handed back rather than skipped, so an import can say which of its
+/// `Scan`s it lost and why, rather than arriving a few short with nothing to
+/// say for it. Blank lines are not entries and are passed over, and a line
+/// that is not UTF-8 is that one line gone wrong.
+///
+/// Stops at the first line the file cannot give up at all, since a reader
+/// that failed once will only go on failing.
+pub struct JournalReader<R = BufReader<File>> {
+    path: PathBuf,
+    reader: R,
+    line: usize,
+    part: Option<u8>,
+    done: bool,
+}
+
+impl JournalReader {
+    /// Open a `Journal.<timestamp>.<part>.log` for reading
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::io(path, None, e))?;
+        Ok(JournalReader::new(path, BufReader::new(file)))
+    }
+}
+
+impl<R: BufRead> JournalReader<R> {
+    /// Read journal lines from anywhere, reporting them as from `path`
+    pub fn new<P: AsRef<Path>>(path: P, reader: R) -> Self {
+        JournalReader {
+            path: path.as_ref().to_path_buf(),
+            reader,
+            line: 0,
+            part: None,
+            done: false,
+        }
+    }
+
+    /// Report a `Fileheader` giving any part but `part`
+    ///
+    /// The part a journal is named for is where it was sorted among the rest,
+    /// so a header that disagrees means it was put in the wrong place. The
+    /// header comes back as the error rather than as an entry.
+    pub fn expect_part(mut self, part: u8) -> Self {
+        self.part = Some(part);
+        self
+    }
+
+    /// The file being read
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl<R: BufRead> Iterator for JournalReader<R> {
+    type Item = Result<Entry<Event>, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let mut bytes = Vec::new();
+            self.line += 1;
+            match self.reader.read_until(b'\n', &mut bytes) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    let read =
+                        parse_bytes(&self.path, self.line, self.part, bytes);
+                    if read.is_some() {
+                        return read;
+                    }
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(LineError {
+                        line: self.line,
+                        text: String::from_utf8_lossy(&bytes).into_owned(),
+                        error: Error::io(&self.path, Some(self.line), e),
+                    }));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Read one journal line as it came off the disk, line ending and all
+///
+/// [`None`] for a blank line, which is not an entry. A line that is not UTF-8
+/// is an [`Error::Io`], as reading it as text would have been.
+pub(crate) fn parse_bytes(
+    path: &Path,
+    line: usize,
+    part: Option<u8>,
+    bytes: Vec<u8>,
+) -> Option<Result<Entry<Event>, LineError>> {
+    let text = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => {
+            let error =
+                io::Error::new(io::ErrorKind::InvalidData, e.utf8_error());
+            return Some(Err(LineError {
+                line,
+                text: String::from_utf8_lossy(e.as_bytes())
+                    .trim_end_matches(['\r', '\n'])
+                    .to_string(),
+                error: Error::io(path, Some(line), error),
+            }));
+        }
+    };
+    if text.trim().is_empty() {
+        return None;
+    }
+    let text = text.trim_end_matches(['\r', '\n']).to_string();
+    Some(parse_part_line(path, line, part, text))
+}
+
+/// Read one journal line, which is line `line` of `path`
+pub(crate) fn parse_line(
+    path: &Path,
+    line: usize,
+    text: String,
+) -> Result<Entry<Event>, LineError> {
+    serde_json::from_str(&text).map_err(|e| LineError {
+        line,
+        error: Error::from_json::<Event, _>(path, Some(line), &text, e),
+        text,
+    })
+}
+
+/// Read one journal line, holding a header to the part expected of it
+pub(crate) fn parse_part_line(
+    path: &Path,
+    line: usize,
+    part: Option<u8>,
+    text: String,
+) -> Result<Entry<Event>, LineError> {
+    let named = match part {
+        Some(named) if text.contains("\"Fileheader\"") => named,
+        _ => return parse_line(path, line, text),
+    };
+    let entry = parse_line(path, line, text.clone())?;
+    match &entry.event {
+        Event::Fileheader(header) if header.part != named => Err(LineError {
+            line,
+            error: Error::Part {
+                path: path.to_path_buf(),
+                line: Some(line),
+                named,
+                header: header.part,
+            },
+            text,
+        }),
+        _ => Ok(entry),
+    }
+}
+
+/// A journal line that did not read, and the line itself
+///
+/// The text is kept as it was so that whatever went wrong can be looked at
+/// again later, or read again by something that knows better.
+#[derive(Debug)]
+pub struct LineError {
+    /// Counted from one, as an editor counts them
+    pub line: usize,
+    /// The line as it was read, without its line ending
+    ///
+    /// Anything in it that was not UTF-8 is `U+FFFD`, the replacement
+    /// character.
+    pub text: String,
+    pub error: Error,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)
+    }
+}
+
+impl std::error::Error for LineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// A journal with something wrong on most of its lines
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const JOURNAL: &str = concat!(
+        r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "Fileheader", "part": 1, "language": "English/UK", "gameversion": "4.0.0.1904", "build": "r308767/r0 " }"#,
+        "\n",
+        r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Undocked", "StationName": "Ray Gateway" }"#,
+        "\r\n",
+        "\n",
+        r#"{ "timestamp": "2026-08-08T12:00:02Z", "event": "Music", "MusicTrack": "Exploration" }"#,
+        "\n",
+        r#"{ "timestamp": "2026-08-08T12:00:03Z", "event": "Shutd"#,
+    );
+
+    /// Every line that is not blank comes back, read or not
+    #[test]
+    fn every_line_is_accounted_for() {
+        let lines = JournalReader::new("Journal.log", Cursor::new(JOURNAL))
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 4);
+        assert!(matches!(
+            lines[0],
+            Ok(Entry {
+                event: Event::Fileheader(_),
+                ..
+            })
+        ));
+        // Read as nothing in particular, which is not the same as lost.
+        assert!(matches!(
+            lines[2],
+            Ok(Entry {
+                event: Event::Unknown { .. },
+                ..
+            })
+        ));
+    }
+
+    /// A line that did not read says which it was and what it said
+    #[test]
+    fn a_line_that_did_not_read_is_kept() {
+        let lines = JournalReader::new("Journal.log", Cursor::new(JOURNAL))
+            .collect::<Vec<_>>();
+
+        let Err(undocked) = &lines[1] else {
+            panic!("an undocking without its market should not read")
+        };
+        assert_eq!(undocked.line, 2);
+        assert!(undocked.text.ends_with(r#""Ray Gateway" }"#));
+        assert_eq!(undocked.error.event(), Some("Undocked"));
+        assert!(matches!(undocked.error, Error::Event { .. }));
+
+        // The blank line still counts, so this is the fifth.
+        let Err(cut_off) = &lines[3] else {
+            panic!("half a line should not read")
+        };
+        assert_eq!(cut_off.line, 5);
+        assert!(matches!(cut_off.error, Error::Json { .. }));
+        assert_eq!(cut_off.error.path(), Path::new("Journal.log"));
+    }
+
+    /// A byte that is not UTF-8 costs its own line and no more
+    ///
+    /// Was taken for the file failing, and ended it there.
+    #[test]
+    fn a_line_that_is_not_utf8_is_one_line() {
+        let mut journal = Vec::new();
+        journal.extend_from_slice(
+            br#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "Commander", "Name": "J"#,
+        );
+        journal.push(0xff);
+        journal.extend_from_slice(br#"meson", "FID": "F1234567" }"#);
+        journal.push(b'\n');
+        journal.extend_from_slice(
+            br#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Shutdown" }"#,
+        );
+
+        let lines = JournalReader::new("Journal.log", Cursor::new(journal))
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 2);
+        let Err(commander) = &lines[0] else {
+            panic!("a line that is not UTF-8 should not read")
+        };
+        assert_eq!(commander.line, 1);
+        assert!(matches!(commander.error, Error::Io { .. }));
+        assert!(commander.text.contains("J\u{fffd}meson"));
+        let Ok(Entry {
+            event: Event::Unknown { event, .. },
+            ..
+        }) = &lines[1]
+        else {
+            panic!("the line after should read")
+        };
+        assert_eq!(event, "Shutdown");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// A route plotted, and the stops on it where they are given
 ///
@@ -7,19 +7,21 @@ use serde::Deserialize;
 /// the stops in `NavRoute.json` beside it, so a log line carries none of
 /// them. EDDN sends the stops in the message. Both are read as this, and the
 /// file is read as an [`Entry`][crate::entry::Entry] of it.
-#[derive(Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
 pub struct NavRoute {
     #[serde(rename = "Route")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub destinations: Vec<Destination>,
 }
 
 /// One stop on a route: a system, where it is, and what burns in the middle
-#[derive(Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Destination {
     pub star_system: String, // TODO: actually a system_address, might change name.
     pub system_address: u64,
+    #[serde(serialize_with = "crate::system::star_pos")]
     pub star_pos: Coordinate,
     pub star_class: String, // TODO: Enum?
 }
@@ -0,0 +1,109 @@
+//! A station's shipyard as the game writes it beside the journal
+//!
+//! The `Shipyard` journal event says a shipyard was opened, and the ships and
+//! their prices go in `Shipyard.json`, which is read as an
+//! [`Entry`][crate::entry::Entry] of the same [`JournalShipyard`].
+
+use crate::entry::market::Shipyard;
+use serde::{Deserialize, Serialize};
+
+/// `Shipyard.json`, or the `Shipyard` event without its price list
+///
+/// Turned into EDDN's [`Shipyard`] with [`From`], which keeps the ship names
+/// and drops their prices.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct JournalShipyard {
+    #[serde(rename = "MarketID")]
+    pub market_id: i64,
+    pub station_name: String,
+    pub star_system: String,
+    /// Whether the commander had Horizons, which some ships need
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizons: Option<bool>,
+    /// Whether the commander could buy a Cobra MkIV, as on EDDN's [`Shipyard`]
+    #[serde(rename = "AllowCobraMkIV")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_cobra_mk_iv: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub price_list: Vec<ShipPrice>,
+}
+
+/// One ship for sale in a [`JournalShipyard`]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ShipPrice {
+    #[serde(rename = "id")]
+    pub id: i64,
+    /// Symbolic, e.g. `federation_corvette`
+    pub ship_type: String,
+    #[serde(rename = "ShipType_Localised")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ship_type_localised: Option<String>,
+    pub ship_price: i64,
+}
+
+impl From<JournalShipyard> for Shipyard {
+    fn from(shipyard: JournalShipyard) -> Self {
+        Shipyard {
+            system_name: shipyard.star_system,
+            station_name: shipyard.station_name,
+            market_id: shipyard.market_id,
+            ships: shipyard
+                .price_list
+                .into_iter()
+                .map(|ship| ship.ship_type)
+                .collect(),
+            allow_cobra_mk_iv: shipyard.allow_cobra_mk_iv,
+        }
+    }
+}
+
+/// `Shipyard.json` as the game writes it
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::read_status_file;
+    use crate::entry::Entry;
+
+    #[test]
+    fn shipyard_json_reads_through_parse_status_file() {
+        let shipyard = read_status_file::<JournalShipyard>(
+            "Shipyard.json",
+            r#"{ "timestamp":"2026-08-08T12:00:00Z", "event":"Shipyard", "MarketID":128016384, "StationName":"Abraham Lincoln", "StarSystem":"Sol", "Horizons":true, "AllowCobraMkIV":false, "PriceList":[
+{ "id":128049249, "ShipType":"sidewinder", "ShipPrice":29946 },
+{ "id":128049375, "ShipType":"federation_corvette", "ShipType_Localised":"Federal Corvette", "ShipPrice":182300416 }
+ ] }"#,
+        )
+        .event;
+        assert_eq!(shipyard.horizons, Some(true));
+        assert_eq!(
+            shipyard.price_list[1].ship_type_localised.as_deref(),
+            Some("Federal Corvette")
+        );
+        assert_eq!(shipyard.price_list[1].ship_price, 182300416);
+
+        let shipyard = Shipyard::from(shipyard);
+        assert_eq!(shipyard.system_name, "Sol");
+        assert_eq!(shipyard.ships, ["sidewinder", "federation_corvette"]);
+        assert_eq!(shipyard.allow_cobra_mk_iv, Some(false));
+    }
+
+    /// The journal event, which points at the file
+    #[test]
+    fn the_event_has_no_price_list() {
+        let entry: Entry<JournalShipyard> = serde_json::from_str(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Shipyard",
+                "MarketID": 128016384,
+                "StationName": "Abraham Lincoln",
+                "StarSystem": "Sol"
+            }"#,
+        )
+        .expect("the Shipyard event should read");
+
+        assert!(entry.event.price_list.is_empty());
+        assert_eq!(entry.event.allow_cobra_mk_iv, None);
+    }
+}
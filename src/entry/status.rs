@@ -0,0 +1,526 @@
+//! What the cockpit shows, rewritten by the game every time it changes
+//!
+//! Unlike the journal, `Status.json` is not a record of anything. It is one
+//! object, overwritten in place, saying how things stand right now: which pips
+//! are where, what the ship is doing, where on a planet it is. Read it again
+//! and it may say something else entirely.
+//!
+//! Most of it is only there when it means something. At the main menu the file
+//! carries its timestamp and nothing else, in a ship there is no oxygen, and
+//! off a planet there is no latitude. Every field but the flags is an
+//! [`Option`] for that reason.
+
+use serde::{Deserialize, Serialize};
+
+/// `Status.json`, read as an [`Entry`][crate::entry::Entry] of this
+///
+/// ```no_run
+/// use elite_journal::entry::{parse_status_file, Status};
+///
+/// let status = parse_status_file::<_, Status>("Status.json").unwrap();
+/// if status.event.flags.docked {
+///     println!("docked");
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Status {
+    #[serde(default)]
+    pub flags: Flags,
+    /// [`Flags2`] came with Odyssey, and is all unset where it is not sent
+    #[serde(default)]
+    pub flags2: Flags2,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pips: Option<Pips>,
+    /// Counted from zero, where the game shows them lettered from A
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fire_group: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gui_focus: Option<GuiFocus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fuel: Option<Fuel>,
+    /// Tonnes of it aboard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cargo: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_state: Option<LegalState>,
+
+    /// Degrees, where [`Flags::has_lat_long`] says there is a surface below
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    /// Metres, from the surface or from the average radius as
+    /// [`Flags::altitude_from_average_radius`] says
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub altitude: Option<f64>,
+    /// Degrees from north
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heading: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_name: Option<String>,
+
+    /// The suit's air, from one down to nothing, where on foot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oxygen: Option<f64>,
+    /// The commander's, from one down to nothing, where on foot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<f64>,
+    /// Kelvin, around the commander where on foot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+}
+
+/// The first of the two bitfields, decoded
+///
+/// Written as a number by the game, and read from and written back as one. A
+/// bit the game sets that is not named here is not kept.
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(from = "u32", into = "u32")]
+pub struct Flags {
+    /// On a landing pad, at a station or a carrier or a settlement
+    pub docked: bool,
+    /// Set down on a planet's surface
+    pub landed: bool,
+    pub landing_gear_down: bool,
+    pub shields_up: bool,
+    pub supercruise: bool,
+    pub flight_assist_off: bool,
+    pub hardpoints_deployed: bool,
+    pub in_wing: bool,
+    pub lights_on: bool,
+    pub cargo_scoop_deployed: bool,
+    pub silent_running: bool,
+    pub scooping_fuel: bool,
+    pub srv_handbrake: bool,
+    pub srv_turret_view: bool,
+    /// Too close to the ship to deploy
+    pub srv_turret_retracted: bool,
+    pub srv_drive_assist: bool,
+    pub fsd_mass_locked: bool,
+    pub fsd_charging: bool,
+    pub fsd_cooldown: bool,
+    /// Under a quarter of a tank
+    pub low_fuel: bool,
+    /// Over a hundred percent heat
+    pub over_heating: bool,
+    /// Whether [`Status::latitude`] and [`Status::longitude`] are sent
+    pub has_lat_long: bool,
+    pub in_danger: bool,
+    pub being_interdicted: bool,
+    pub in_main_ship: bool,
+    pub in_fighter: bool,
+    pub in_srv: bool,
+    pub hud_analysis_mode: bool,
+    pub night_vision: bool,
+    /// Whether [`Status::altitude`] is measured from the average radius
+    /// rather than from the ground, which it is high enough up
+    pub altitude_from_average_radius: bool,
+    pub fsd_jump: bool,
+    pub srv_high_beam: bool,
+}
+
+impl From<u32> for Flags {
+    fn from(bits: u32) -> Self {
+        let set = |bit: u32| bits & (1 << bit) != 0;
+        Flags {
+            docked: set(0),
+            landed: set(1),
+            landing_gear_down: set(2),
+            shields_up: set(3),
+            supercruise: set(4),
+            flight_assist_off: set(5),
+            hardpoints_deployed: set(6),
+            in_wing: set(7),
+            lights_on: set(8),
+            cargo_scoop_deployed: set(9),
+            silent_running: set(10),
+            scooping_fuel: set(11),
+            srv_handbrake: set(12),
+            srv_turret_view: set(13),
+            srv_turret_retracted: set(14),
+            srv_drive_assist: set(15),
+            fsd_mass_locked: set(16),
+            fsd_charging: set(17),
+            fsd_cooldown: set(18),
+            low_fuel: set(19),
+            over_heating: set(20),
+            has_lat_long: set(21),
+            in_danger: set(22),
+            being_interdicted: set(23),
+            in_main_ship: set(24),
+            in_fighter: set(25),
+            in_srv: set(26),
+            hud_analysis_mode: set(27),
+            night_vision: set(28),
+            altitude_from_average_radius: set(29),
+            fsd_jump: set(30),
+            srv_high_beam: set(31),
+        }
+    }
+}
+
+impl From<Flags> for u32 {
+    fn from(flags: Flags) -> Self {
+        bits(&[
+            flags.docked,
+            flags.landed,
+            flags.landing_gear_down,
+            flags.shields_up,
+            flags.supercruise,
+            flags.flight_assist_off,
+            flags.hardpoints_deployed,
+            flags.in_wing,
+            flags.lights_on,
+            flags.cargo_scoop_deployed,
+            flags.silent_running,
+            flags.scooping_fuel,
+            flags.srv_handbrake,
+            flags.srv_turret_view,
+            flags.srv_turret_retracted,
+            flags.srv_drive_assist,
+            flags.fsd_mass_locked,
+            flags.fsd_charging,
+            flags.fsd_cooldown,
+            flags.low_fuel,
+            flags.over_heating,
+            flags.has_lat_long,
+            flags.in_danger,
+            flags.being_interdicted,
+            flags.in_main_ship,
+            flags.in_fighter,
+            flags.in_srv,
+            flags.hud_analysis_mode,
+            flags.night_vision,
+            flags.altitude_from_average_radius,
+            flags.fsd_jump,
+            flags.srv_high_beam,
+        ])
+    }
+}
+
+/// The second of the two bitfields, which Odyssey added for life on foot
+#[derive(
+    Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq,
+)]
+#[serde(from = "u32", into = "u32")]
+pub struct Flags2 {
+    pub on_foot: bool,
+    pub in_taxi: bool,
+    /// In someone else's ship
+    pub in_multicrew: bool,
+    pub on_foot_in_station: bool,
+    pub on_foot_on_planet: bool,
+    pub aim_down_sight: bool,
+    pub low_oxygen: bool,
+    pub low_health: bool,
+    pub cold: bool,
+    pub hot: bool,
+    pub very_cold: bool,
+    pub very_hot: bool,
+    /// Falling from a dropship, with the suit slowing the fall
+    pub glide_mode: bool,
+    pub on_foot_in_hangar: bool,
+    pub on_foot_social_space: bool,
+    pub on_foot_exterior: bool,
+    pub breathable_atmosphere: bool,
+    pub telepresence_multicrew: bool,
+    pub physical_multicrew: bool,
+    pub fsd_hyperdrive_charging: bool,
+}
+
+impl From<u32> for Flags2 {
+    fn from(bits: u32) -> Self {
+        let set = |bit: u32| bits & (1 << bit) != 0;
+        Flags2 {
+            on_foot: set(0),
+            in_taxi: set(1),
+            in_multicrew: set(2),
+            on_foot_in_station: set(3),
+            on_foot_on_planet: set(4),
+            aim_down_sight: set(5),
+            low_oxygen: set(6),
+            low_health: set(7),
+            cold: set(8),
+            hot: set(9),
+            very_cold: set(10),
+            very_hot: set(11),
+            glide_mode: set(12),
+            on_foot_in_hangar: set(13),
+            on_foot_social_space: set(14),
+            on_foot_exterior: set(15),
+            breathable_atmosphere: set(16),
+            telepresence_multicrew: set(17),
+            physical_multicrew: set(18),
+            fsd_hyperdrive_charging: set(19),
+        }
+    }
+}
+
+impl From<Flags2> for u32 {
+    fn from(flags: Flags2) -> Self {
+        bits(&[
+            flags.on_foot,
+            flags.in_taxi,
+            flags.in_multicrew,
+            flags.on_foot_in_station,
+            flags.on_foot_on_planet,
+            flags.aim_down_sight,
+            flags.low_oxygen,
+            flags.low_health,
+            flags.cold,
+            flags.hot,
+            flags.very_cold,
+            flags.very_hot,
+            flags.glide_mode,
+            flags.on_foot_in_hangar,
+            flags.on_foot_social_space,
+            flags.on_foot_exterior,
+            flags.breathable_atmosphere,
+            flags.telepresence_multicrew,
+            flags.physical_multicrew,
+            flags.fsd_hyperdrive_charging,
+        ])
+    }
+}
+
+/// Pack flags back into a bitfield, the first flag the lowest bit
+fn bits(flags: &[bool]) -> u32 {
+    flags
+        .iter()
+        .enumerate()
+        .filter(|(_, set)| **set)
+        .fold(0, |bits, (bit, _)| bits | 1 << bit)
+}
+
+/// Where the power distributor's pips are, counted in halves
+///
+/// Sent as `[sys, eng, wep]`. A pip is two here, so the six a ship has to
+/// share out come to twelve across the three.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "[u8; 3]", into = "[u8; 3]")]
+pub struct Pips {
+    pub systems: u8,
+    pub engines: u8,
+    pub weapons: u8,
+}
+
+impl From<[u8; 3]> for Pips {
+    fn from([systems, engines, weapons]: [u8; 3]) -> Self {
+        Pips {
+            systems,
+            engines,
+            weapons,
+        }
+    }
+}
+
+impl From<Pips> for [u8; 3] {
+    fn from(pips: Pips) -> Self {
+        [pips.systems, pips.engines, pips.weapons]
+    }
+}
+
+/// Which screen has the commander's attention, sent as a number
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum GuiFocus {
+    NoFocus,
+    /// The panel on the right
+    InternalPanel,
+    /// The panel on the left
+    ExternalPanel,
+    /// The panel above
+    CommsPanel,
+    /// The panel below
+    RolePanel,
+    StationServices,
+    GalaxyMap,
+    SystemMap,
+    Orrery,
+    /// The full spectrum scanner
+    Fss,
+    /// The surface scanner
+    Saa,
+    Codex,
+    /// A screen added since, by its number
+    Other(u8),
+}
+
+impl From<u8> for GuiFocus {
+    fn from(focus: u8) -> Self {
+        match focus {
+            0 => GuiFocus::NoFocus,
+            1 => GuiFocus::InternalPanel,
+            2 => GuiFocus::ExternalPanel,
+            3 => GuiFocus::CommsPanel,
+            4 => GuiFocus::RolePanel,
+            5 => GuiFocus::StationServices,
+            6 => GuiFocus::GalaxyMap,
+            7 => GuiFocus::SystemMap,
+            8 => GuiFocus::Orrery,
+            9 => GuiFocus::Fss,
+            10 => GuiFocus::Saa,
+            11 => GuiFocus::Codex,
+            other => GuiFocus::Other(other),
+        }
+    }
+}
+
+impl From<GuiFocus> for u8 {
+    fn from(focus: GuiFocus) -> Self {
+        match focus {
+            GuiFocus::NoFocus => 0,
+            GuiFocus::InternalPanel => 1,
+            GuiFocus::ExternalPanel => 2,
+            GuiFocus::CommsPanel => 3,
+            GuiFocus::RolePanel => 4,
+            GuiFocus::StationServices => 5,
+            GuiFocus::GalaxyMap => 6,
+            GuiFocus::SystemMap => 7,
+            GuiFocus::Orrery => 8,
+            GuiFocus::Fss => 9,
+            GuiFocus::Saa => 10,
+            GuiFocus::Codex => 11,
+            GuiFocus::Other(other) => other,
+        }
+    }
+}
+
+/// How much is in the tanks, in tonnes
+///
+/// Not [`crate::ship::Fuel`], which is what kind of fuel it is.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Fuel {
+    #[serde(rename = "FuelMain")]
+    pub main: f64,
+    /// The small tank the thrusters draw on, topped up from the main one
+    #[serde(rename = "FuelReservoir")]
+    pub reservoir: f64,
+}
+
+/// Where the commander stands with the law where they are
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum LegalState {
+    Clean,
+    IllegalCargo,
+    Speeding,
+    Wanted,
+    Hostile,
+    PassengerWanted,
+    Warrant,
+    /// A state added since, by its name
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Status files as the game writes them, at rest and on the move
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::read_status_file;
+    use crate::entry::Entry;
+
+    /// Docked at a station with the lights on and half a tank
+    const DOCKED: &str = r#"{ "timestamp":"2026-08-08T12:00:00Z", "event":"Status", "Flags":16842765, "Flags2":0, "Pips":[4,8,0], "FireGroup":0, "GuiFocus":5, "Fuel":{ "FuelMain":16.0, "FuelReservoir":0.5 }, "Cargo":4.0, "LegalState":"Clean", "Balance":120000 }"#;
+
+    #[test]
+    fn a_status_file_reads_through_parse_status_file() {
+        let status = read_status_file::<Status>("Status.json", DOCKED).event;
+        assert!(status.flags.docked);
+        assert!(status.flags.landing_gear_down);
+        assert!(status.flags.shields_up);
+        assert!(status.flags.fsd_mass_locked);
+        assert!(status.flags.in_main_ship);
+        assert!(!status.flags.supercruise);
+        assert!(!status.flags2.on_foot);
+        assert_eq!(
+            status.pips,
+            Some(Pips {
+                systems: 4,
+                engines: 8,
+                weapons: 0
+            })
+        );
+        assert_eq!(status.gui_focus, Some(GuiFocus::StationServices));
+        assert_eq!(status.fuel.map(|fuel| fuel.main), Some(16.0));
+        assert_eq!(status.legal_state, Some(LegalState::Clean));
+        assert_eq!(status.latitude, None);
+    }
+
+    /// Walking about a settlement, which the second bitfield says
+    #[test]
+    fn on_foot_is_in_the_second_bitfield() {
+        let status = serde_json::from_str::<Entry<Status>>(
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Status",
+                "Flags": 2097152,
+                "Flags2": 98321,
+                "Oxygen": 1.0,
+                "Health": 1.0,
+                "Temperature": 293.5,
+                "SelectedWeapon": "$humanoid_fists_name;",
+                "Gravity": 0.17,
+                "LegalState": "Clean",
+                "Latitude": -12.25,
+                "Longitude": 44.5,
+                "Heading": 271,
+                "BodyName": "Sol 4",
+                "GuiFocus": 14
+            }"#,
+        )
+        .expect("status should read")
+        .event;
+
+        assert!(status.flags.has_lat_long);
+        assert!(!status.flags.in_main_ship);
+        assert!(status.flags2.on_foot);
+        assert!(status.flags2.on_foot_on_planet);
+        assert!(status.flags2.on_foot_exterior);
+        assert!(!status.flags2.on_foot_in_station);
+        assert!(status.flags2.breathable_atmosphere);
+        assert!(!status.flags2.in_taxi);
+        assert_eq!(status.heading, Some(271.0));
+        assert_eq!(status.body_name.as_deref(), Some("Sol 4"));
+        assert_eq!(status.temperature, Some(293.5));
+        assert_eq!(status.gui_focus, Some(GuiFocus::Other(14)));
+        assert_eq!(status.pips, None);
+    }
+
+    /// At the main menu there is a timestamp and nothing else
+    #[test]
+    fn the_main_menu_says_nothing() {
+        let status = serde_json::from_str::<Entry<Status>>(
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "Status" }"#,
+        )
+        .expect("status should read")
+        .event;
+
+        assert_eq!(status, Status::default());
+    }
+
+    /// Every flag is its own bit, and goes back as the bit it came from
+    #[test]
+    fn the_flags_are_written_back_as_the_bits_they_were() {
+        for bit in 0..32 {
+            let flags = Flags::from(1 << bit);
+            assert_ne!(flags, Flags::default(), "bit {} is not read", bit);
+            assert_eq!(u32::from(flags), 1 << bit);
+        }
+        for bit in 0..20 {
+            let flags = Flags2::from(1 << bit);
+            assert_ne!(flags, Flags2::default(), "bit {} is not read", bit);
+            assert_eq!(u32::from(flags), 1 << bit);
+        }
+
+        let status = serde_json::from_str::<Entry<Status>>(DOCKED).unwrap();
+        let written = serde_json::to_value(&status).unwrap();
+        assert_eq!(written["Flags"], 16842765);
+        assert_eq!(written["Pips"], serde_json::json!([4, 8, 0]));
+        assert_eq!(written["GuiFocus"], 5);
+    }
+}
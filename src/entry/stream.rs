@@ -0,0 +1,272 @@
+//! The parse functions over again, for reading on an async runtime
+//!
+//! Each does what its namesake in [`entry`][crate::entry] does, without
+//! holding a worker thread while the disk answers. A journal comes back as a
+//! [`Stream`] of lines rather than a `Vec` of them, so an ingest task can start
+//! on the first entry before the last is read.
+//!
+//! The streams are not [`Unpin`]. Pin one with [`futures::pin_mut`] or
+//! [`StreamExt::boxed`] before calling [`StreamExt::next`] on it.
+
+use crate::entry::dir;
+use crate::entry::reader::parse_bytes;
+use crate::entry::{Entry, Event, JournalFile, LineError};
+use crate::Error;
+use async_std::fs::{read_dir, read_to_string, File};
+use async_std::io::prelude::BufReadExt;
+use async_std::io::BufReader;
+use futures::stream::{self, Stream, StreamExt};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Parse a single file's worth of journal entries, as they are read
+pub async fn parse_journal_file<P: AsRef<Path>>(
+    path: P,
+) -> Result<impl Stream<Item = Result<Entry<Event>, LineError>>, Error> {
+    Ok(lines(Lines::open(path.as_ref().to_path_buf(), None).await?))
+}
+
+/// Parse all journals file's entries in a directory, as they are read
+///
+/// In the order the game wrote them, as [`journal_files`] puts them. Each file
+/// is opened only when the one before it is finished, and one that cannot be
+/// opened by then takes its place in the stream as an error on line `0`,
+/// which is to say before any line of it was read.
+///
+/// [`journal_files`]: crate::entry::journal_files
+pub async fn parse_journal_dir<P: AsRef<Path>>(
+    path: P,
+) -> Result<impl Stream<Item = Result<Entry<Event>, LineError>>, Error> {
+    let files = journal_files(path.as_ref()).await?;
+    Ok(stream::iter(files)
+        .then(|file| Lines::open(file.path, Some(file.part)))
+        .flat_map(|opened| match opened {
+            Ok(opened) => lines(opened).left_stream(),
+            Err(error) => stream::iter(Some(Err(LineError {
+                line: 0,
+                text: String::new(),
+                error,
+            })))
+            .right_stream(),
+        }))
+}
+
+/// Parse a status file entry
+pub async fn parse_status_file<P: AsRef<Path>, E>(
+    path: P,
+) -> Result<Entry<E>, Error>
+where
+    for<'de> E: Deserialize<'de>,
+{
+    let path = path.as_ref();
+    let text = read_to_string(path)
+        .await
+        .map_err(|e| Error::io(path, None, e))?;
+    serde_json::from_str(&text)
+        .map_err(|e| Error::from_json::<E, _>(path, None, &text, e))
+}
+
+/// Every journal in `dir`, in the order the game wrote them
+///
+/// Listed without blocking, and then put in order as [`journal_files`] does.
+///
+/// [`journal_files`]: crate::entry::journal_files
+async fn journal_files(dir: &Path) -> Result<Vec<JournalFile>, Error> {
+    let mut paths = Vec::new();
+    let mut entries =
+        read_dir(dir).await.map_err(|e| Error::io(dir, None, e))?;
+    while let Some(entry) = entries.next().await {
+        let entry = entry.map_err(|e| Error::io(dir, None, e))?;
+        let path: PathBuf = entry.path().into();
+        let file_type = entry
+            .file_type()
+            .await
+            .map_err(|e| Error::io(&path, None, e))?;
+        if file_type.is_file() {
+            paths.push(path);
+        }
+    }
+    Ok(dir::in_order(paths))
+}
+
+/// A journal open for reading, and how far into it we are
+struct Lines {
+    path: PathBuf,
+    reader: BufReader<File>,
+    part: Option<u8>,
+    line: usize,
+}
+
+impl Lines {
+    async fn open(path: PathBuf, part: Option<u8>) -> Result<Self, Error> {
+        let file = File::open(&path)
+            .await
+            .map_err(|e| Error::io(&path, None, e))?;
+        Ok(Lines {
+            path,
+            reader: BufReader::new(file),
+            part,
+            line: 0,
+        })
+    }
+}
+
+/// What [`JournalReader`][crate::entry::JournalReader] does, awaiting each line
+fn lines(lines: Lines) -> impl Stream<Item = Result<Entry<Event>, LineError>> {
+    stream::unfold(Some(lines), |lines| async move {
+        let mut lines = lines?;
+        loop {
+            let mut bytes = Vec::new();
+            lines.line += 1;
+            match lines.reader.read_until(b'\n', &mut bytes).await {
+                Ok(0) => return None,
+                Ok(_) => {
+                    let read =
+                        parse_bytes(&lines.path, lines.line, lines.part, bytes);
+                    if let Some(entry) = read {
+                        return Some((entry, Some(lines)));
+                    }
+                }
+                Err(e) => {
+                    let error = LineError {
+                        line: lines.line,
+                        error: Error::io(&lines.path, Some(lines.line), e),
+                        text: String::from_utf8_lossy(&bytes).into_owned(),
+                    };
+                    return Some((Err(error), None));
+                }
+            }
+        }
+    })
+}
+
+/// The same journals the blocking reads are tested on, read on a runtime
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::{header, scratch_dir};
+    use crate::entry::NavRoute;
+    use async_std::task::block_on;
+    use std::fs;
+
+    #[test]
+    fn a_journal_streams_a_line_at_a_time() {
+        let dir = scratch_dir("stream-file");
+        let path = dir.join("Journal.2026-08-08T120000.01.log");
+        fs::write(
+            &path,
+            header(1, "2026-08-08T12:00:00Z")
+                + "\n"
+                + r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Undocked" }"#,
+        )
+        .unwrap();
+
+        let lines = block_on(async {
+            parse_journal_file(&path)
+                .await
+                .expect("journal should open")
+                .collect::<Vec<_>>()
+                .await
+        });
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].is_ok());
+        let Err(undocked) = &lines[1] else {
+            panic!("an undocking with nothing in it should not read")
+        };
+        assert_eq!(undocked.line, 3);
+        assert_eq!(undocked.error.event(), Some("Undocked"));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    /// A byte that is not UTF-8 costs its own line, as it does blocking
+    #[test]
+    fn a_line_that_is_not_utf8_streams_as_one_line() {
+        let dir = scratch_dir("stream-utf8");
+        let path = dir.join("Journal.2026-08-08T120000.01.log");
+        let mut journal = header(1, "2026-08-08T12:00:00Z").into_bytes();
+        journal.extend_from_slice(b"{ \"event\": \"\xff\" }\n");
+        journal.extend_from_slice(
+            br#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Shutdown" }"#,
+        );
+        fs::write(&path, journal).unwrap();
+
+        let lines = block_on(async {
+            parse_journal_file(&path)
+                .await
+                .expect("journal should open")
+                .collect::<Vec<_>>()
+                .await
+        });
+
+        assert_eq!(lines.len(), 3);
+        let Err(bad) = &lines[1] else {
+            panic!("a line that is not UTF-8 should not read")
+        };
+        assert!(matches!(bad.error, Error::Io { .. }));
+        assert!(lines[2].is_ok());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn a_directory_streams_in_the_order_it_was_written() {
+        let dir = scratch_dir("stream-dir");
+        fs::write(
+            dir.join("Journal.2022-11-30T201500.01.log"),
+            header(1, "2022-11-30T20:15:00Z"),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("Journal.170101120000.01.log"),
+            header(2, "2017-01-01T12:00:00Z"),
+        )
+        .unwrap();
+
+        let lines = block_on(async {
+            parse_journal_dir(&dir)
+                .await
+                .expect("directory should list")
+                .collect::<Vec<_>>()
+                .await
+        });
+
+        assert_eq!(lines.len(), 2);
+        // Named part one, and its header says two.
+        let Err(wrong) = &lines[0] else {
+            panic!("a header giving the wrong part should not read")
+        };
+        assert!(matches!(wrong.error, Error::Part { .. }));
+        assert_eq!(
+            lines[1]
+                .as_ref()
+                .map(|entry| entry.timestamp.to_rfc3339())
+                .ok(),
+            Some("2022-11-30T20:15:00+00:00".to_string())
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn a_status_file_reads_without_blocking() {
+        let dir = scratch_dir("stream-status");
+        let path = dir.join("NavRoute.json");
+        fs::write(
+            &path,
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "NavRoute" }"#,
+        )
+        .unwrap();
+
+        let route = block_on(parse_status_file::<_, NavRoute>(&path))
+            .expect("route should read");
+        assert!(route.event.destinations.is_empty());
+
+        let missing =
+            block_on(parse_status_file::<_, NavRoute>(dir.join("Cargo.json")));
+        assert!(matches!(missing, Err(Error::Io { .. })));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,399 @@
+use crate::entry::reader::parse_bytes;
+use crate::entry::{Entry, Event, JournalFile, LineError};
+use crate::Error;
+use std::fs::{read_dir, File};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::Duration;
+
+/// The journal the game is writing, followed as it writes it
+///
+/// Starts at the top of the newest `Journal.*.log` in a directory, so the
+/// `Fileheader` and `LoadGame` a session opens with are read like everything
+/// after them, and then waits for more. A line the game is halfway through
+/// writing is held back until its end arrives rather than reported as broken,
+/// even where it stops partway through a character.
+///
+/// The game starts a new file when a session runs long, carrying the `part`
+/// count on in its `Fileheader`, and another when it is restarted, back at
+/// part one. Either is followed once the file it was on has run dry and the
+/// new one has its header written. A journal that turns up with a lower part
+/// than the current one, or named as started before it, is an old file being
+/// moved about, and is left alone.
+///
+/// As an [`Iterator`] it never ends, sleeping between looks at the disk. Use
+/// [`JournalTail::poll`] to take only what is there already.
+pub struct JournalTail {
+    dir: PathBuf,
+    current: Option<Current>,
+    interval: Duration,
+}
+
+/// The file being followed, and how far into it we are
+struct Current {
+    path: PathBuf,
+    reader: BufReader<File>,
+    part: Option<u8>,
+    line: usize,
+    partial: Vec<u8>,
+}
+
+impl JournalTail {
+    /// Follow the journals in `dir`, starting with the newest
+    ///
+    /// A directory with no journal in it yet is followed all the same, and the
+    /// first one written there is read from the top.
+    pub fn new<P: AsRef<Path>>(dir: P) -> Result<Self, Error> {
+        let dir = dir.as_ref().to_path_buf();
+        let current = match newest_journal(&dir)? {
+            Some(path) => Some(Current::open(path)?),
+            None => None,
+        };
+        Ok(JournalTail {
+            dir,
+            current,
+            interval: Duration::from_millis(250),
+        })
+    }
+
+    /// How long to wait between looks at the disk, a quarter second unless set
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The journal being followed, if one has been written yet
+    pub fn path(&self) -> Option<&Path> {
+        self.current.as_ref().map(|current| current.path.as_path())
+    }
+
+    /// The `part` of the journal being followed, once its header is read
+    pub fn part(&self) -> Option<u8> {
+        self.current.as_ref().and_then(|current| current.part)
+    }
+
+    /// The next line written, or [`None`] if nothing new has been written
+    ///
+    /// Never waits. A directory that cannot be looked in just now leaves the
+    /// file being followed as it is, and is looked in again next time.
+    pub fn poll(&mut self) -> Option<Result<Entry<Event>, LineError>> {
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some(line) = current.next_line() {
+                    return Some(line);
+                }
+            }
+            if !self.switch() {
+                return None;
+            }
+        }
+    }
+
+    /// Move on to a newer journal, if the game has started one
+    fn switch(&mut self) -> bool {
+        let Ok(Some(path)) = newest_journal(&self.dir) else {
+            return false;
+        };
+        let Some(next) = JournalFile::parse(&path) else {
+            return false;
+        };
+        let part = match &self.current {
+            Some(current) if current.path == path => return false,
+            Some(current)
+                if JournalFile::parse(&current.path)
+                    .is_some_and(|now| next <= now) =>
+            {
+                return false
+            }
+            Some(current) => current.part,
+            None => None,
+        };
+        let Some(header) = header_part(&path) else {
+            return false;
+        };
+        if part.is_some_and(|part| header != 1 && header <= part) {
+            return false;
+        }
+        match Current::open(path) {
+            Ok(current) => {
+                self.current = Some(current);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+impl Iterator for JournalTail {
+    type Item = Result<Entry<Event>, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(line) = self.poll() {
+                return Some(line);
+            }
+            sleep(self.interval);
+        }
+    }
+}
+
+impl Current {
+    fn open(path: PathBuf) -> Result<Self, Error> {
+        let file = File::open(&path).map_err(|e| Error::io(&path, None, e))?;
+        Ok(Current {
+            path,
+            reader: BufReader::new(file),
+            part: None,
+            line: 0,
+            partial: Vec::new(),
+        })
+    }
+
+    /// The next whole line, read if it is there
+    fn next_line(&mut self) -> Option<Result<Entry<Event>, LineError>> {
+        loop {
+            match self.reader.read_until(b'\n', &mut self.partial) {
+                Ok(0) => return None,
+                Ok(_) if !self.partial.ends_with(b"\n") => return None,
+                Ok(_) => {
+                    self.line += 1;
+                    let bytes = std::mem::take(&mut self.partial);
+                    let Some(entry) =
+                        parse_bytes(&self.path, self.line, None, bytes)
+                    else {
+                        continue;
+                    };
+                    if let Ok(Entry {
+                        event: Event::Fileheader(header),
+                        ..
+                    }) = &entry
+                    {
+                        self.part = Some(header.part);
+                    }
+                    return Some(entry);
+                }
+                Err(e) => {
+                    let line = self.line + 1;
+                    let text = std::mem::take(&mut self.partial);
+                    return Some(Err(LineError {
+                        line,
+                        text: String::from_utf8_lossy(&text).into_owned(),
+                        error: Error::io(&self.path, Some(line), e),
+                    }));
+                }
+            }
+        }
+    }
+}
+
+/// The most recently written `Journal.*.log` in `dir`
+///
+/// By when it was last written to, since the two ways the game has named them
+/// do not sort against each other. Ties go to the later name.
+fn newest_journal(dir: &Path) -> Result<Option<PathBuf>, Error> {
+    let mut newest = None;
+    for entry in read_dir(dir).map_err(|e| Error::io(dir, None, e))? {
+        let entry = entry.map_err(|e| Error::io(dir, None, e))?;
+        let path = entry.path();
+        if !is_journal(&path) {
+            continue;
+        }
+        let Ok(modified) = entry.metadata().and_then(|m| m.modified()) else {
+            continue;
+        };
+        if newest
+            .as_ref()
+            .is_none_or(|(at, newest)| (modified, &path) > (*at, newest))
+        {
+            newest = Some((modified, path));
+        }
+    }
+    Ok(newest.map(|(_, path)| path))
+}
+
+fn is_journal(path: &Path) -> bool {
+    path.is_file() && JournalFile::parse(path).is_some()
+}
+
+/// The `part` a journal's header gives, once the game has written it
+fn header_part(path: &Path) -> Option<u8> {
+    let mut text = Vec::new();
+    BufReader::new(File::open(path).ok()?)
+        .read_until(b'\n', &mut text)
+        .ok()?;
+    if !text.ends_with(b"\n") {
+        return None;
+    }
+    match serde_json::from_slice::<Entry<Event>>(&text).ok()?.event {
+        Event::Fileheader(header) => Some(header.part),
+        _ => None,
+    }
+}
+
+/// A journal appended to a piece at a time, as the game does it
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::{header, scratch_dir};
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+
+    fn append(path: &Path, text: &str) {
+        append_bytes(path, text.as_bytes())
+    }
+
+    fn append_bytes(path: &Path, bytes: &[u8]) {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(bytes))
+            .expect("journal should append");
+    }
+
+    const MUSIC: &str = r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Music", "MusicTrack": "Exploration" }"#;
+
+    /// Half a line is waited on, and read whole once the rest arrives
+    #[test]
+    fn a_line_half_written_is_waited_for() {
+        let dir = scratch_dir("tail-partial");
+        let journal = dir.join("Journal.2026-08-08T120000.01.log");
+        append(&journal, &header(1, "2026-08-08T12:00:00Z"));
+        append(&journal, &MUSIC[..30]);
+
+        let mut tail = JournalTail::new(&dir).expect("tail should start");
+        assert!(matches!(
+            tail.poll(),
+            Some(Ok(Entry {
+                event: Event::Fileheader(_),
+                ..
+            }))
+        ));
+        assert_eq!(tail.part(), Some(1));
+        assert!(tail.poll().is_none(), "read half a line");
+
+        append(&journal, &MUSIC[30..]);
+        assert!(tail.poll().is_none(), "read a line with no end");
+        append(&journal, "\n");
+        assert!(matches!(
+            tail.poll(),
+            Some(Ok(Entry {
+                event: Event::Unknown { .. },
+                ..
+            }))
+        ));
+        assert!(tail.poll().is_none());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    /// A line cut off partway through a character is waited on the same
+    ///
+    /// Was read as text as it came, which gave up on the half a character,
+    /// reported the line broken and lost the bytes it had.
+    #[test]
+    fn a_character_half_written_is_waited_for() {
+        let dir = scratch_dir("tail-utf8");
+        let journal = dir.join("Journal.2026-08-08T120000.01.log");
+        append(&journal, &header(1, "2026-08-08T12:00:00Z"));
+        let commander = concat!(
+            r#"{ "timestamp": "2026-08-08T12:00:01Z", "event": "Commander", "#,
+            "\"FID\": \"F1234567\", \"Name\": \"Jos\u{e9}\" }",
+            "\n"
+        )
+        .as_bytes();
+        // Up to and including the first of the two bytes of the é.
+        let cut = commander.iter().position(|&b| b == 0xc3).unwrap() + 1;
+        append_bytes(&journal, &commander[..cut]);
+
+        let mut tail = JournalTail::new(&dir).expect("tail should start");
+        assert!(tail.poll().is_some());
+        assert!(tail.poll().is_none(), "read half a character");
+
+        append_bytes(&journal, &commander[cut..]);
+        let Some(Ok(Entry {
+            event: Event::Commander(commander),
+            ..
+        })) = tail.poll()
+        else {
+            panic!("the commander should read once written")
+        };
+        assert_eq!(commander.name, "Jos\u{e9}");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    /// The next part is followed once the game has written its header
+    #[test]
+    fn the_next_part_is_followed() {
+        let dir = scratch_dir("tail-parts");
+        let first = dir.join("Journal.2026-08-08T120000.01.log");
+        append(&first, &header(1, "2026-08-08T12:00:00Z"));
+
+        let mut tail = JournalTail::new(&dir).expect("tail should start");
+        assert!(tail.poll().is_some());
+        assert!(tail.poll().is_none());
+
+        // Created, and nothing written to it yet.
+        let second = dir.join("Journal.2026-08-08T120000.02.log");
+        append(&second, "");
+        assert!(tail.poll().is_none());
+        assert_eq!(tail.path(), Some(first.as_path()));
+
+        append(&second, &header(2, "2026-08-08T12:00:00Z"));
+        append(&second, &format!("{}\n", MUSIC));
+        assert!(matches!(
+            tail.poll(),
+            Some(Ok(Entry {
+                event: Event::Fileheader(_),
+                ..
+            }))
+        ));
+        assert_eq!(tail.path(), Some(second.as_path()));
+        assert_eq!(tail.part(), Some(2));
+        assert!(matches!(tail.poll(), Some(Ok(_))));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    /// A part one named as started earlier is an old journal put back
+    ///
+    /// Was followed for being newer on disk and a part one, and the session
+    /// being written was left for one long over.
+    #[test]
+    fn an_older_journal_put_back_is_left_alone() {
+        let dir = scratch_dir("tail-older");
+        let current = dir.join("Journal.2026-08-08T120000.01.log");
+        append(&current, &header(1, "2026-08-08T12:00:00Z"));
+
+        let mut tail = JournalTail::new(&dir).expect("tail should start");
+        assert!(tail.poll().is_some());
+
+        let older = dir.join("Journal.2026-08-01T120000.01.log");
+        append(&older, &header(1, "2026-08-01T12:00:00Z"));
+        append(&older, &format!("{}\n", MUSIC));
+        assert!(tail.poll().is_none(), "read the older journal");
+        assert_eq!(tail.path(), Some(current.as_path()));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    /// An empty directory is waited on until the game writes to it
+    #[test]
+    fn an_empty_directory_waits_for_a_journal() {
+        let dir = scratch_dir("tail-empty");
+        let mut tail = JournalTail::new(&dir).expect("tail should start");
+        assert!(tail.poll().is_none());
+        assert_eq!(tail.path(), None);
+
+        append(
+            &dir.join("Journal.2026-08-08T120000.01.log"),
+            &header(1, "2026-08-08T12:00:00Z"),
+        );
+        assert!(tail.poll().is_some());
+        assert_eq!(tail.part(), Some(1));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,66 @@
+//! What the tests of files on disk have in common
+
+use crate::entry::incremental::{Event, Fold};
+use crate::entry::{parse_status_file, Entry};
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+
+/// Somewhere in the temporary directory nobody else is writing to
+pub(crate) fn scratch_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "elite_journal-{}-{}",
+        std::process::id(),
+        name
+    ))
+}
+
+/// A file nobody else is writing, holding `contents`
+pub(crate) fn scratch_file(name: &str, contents: &str) -> PathBuf {
+    let path = scratch_path(name);
+    fs::write(&path, contents).expect("scratch file should write");
+    path
+}
+
+/// A directory nobody else is writing journals into, empty to start with
+pub(crate) fn scratch_dir(name: &str) -> PathBuf {
+    let dir = scratch_path(name);
+    fs::remove_dir_all(&dir).ok();
+    fs::create_dir_all(&dir).expect("scratch directory should create");
+    dir
+}
+
+/// A status file the game might have written, as read from the disk
+pub(crate) fn read_status_file<T>(name: &str, contents: &str) -> Entry<T>
+where
+    for<'de> T: Deserialize<'de>,
+{
+    let path = scratch_file(name, contents);
+    let read = parse_status_file::<_, T>(&path);
+    fs::remove_file(&path).ok();
+    read.unwrap_or_else(|e| panic!("{} should read: {}", name, e))
+}
+
+/// The `Fileheader` a journal opens with, as its first line
+pub(crate) fn header(part: u8, timestamp: &str) -> String {
+    format!(
+        concat!(
+            r#"{{ "timestamp": "{0}", "event": "Fileheader", "#,
+            r#""part": {1}, "language": "English/UK", "#,
+            r#""gameversion": "4.0.0.1904", "build": "r308767/r0 " }}"#,
+            "\n",
+        ),
+        timestamp, part
+    )
+}
+
+/// Whatever `lines` of a journal leave `T` knowing, from nothing
+pub(crate) fn fold<T: Fold>(lines: &[&str]) -> T {
+    let mut folded = T::default();
+    for line in lines {
+        let entry = serde_json::from_str::<Entry<Event>>(line)
+            .unwrap_or_else(|e| panic!("{} should read: {}", line, e));
+        folded.apply(&entry);
+    }
+    folded
+}
@@ -0,0 +1,376 @@
+use serde::Deserialize;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Anything that stops a journal or status file from being read
+///
+/// Four ways for it to go wrong, which want four different answers from
+/// whoever is reading. The disk failing is nothing to do with the game. A
+/// line that is not JSON at all is usually the game caught halfway through
+/// writing it. An event that will not read is the game having changed a
+/// field under us, and is worth a bug report. An event with no variant to read
+/// it into is one the reader chose to leave out, and can be passed over. The
+/// journal's own event has no such gap, and keeps any it does not know as
+/// [`Event::Unknown`]. A directory of journals can go wrong one more way, with
+/// a file named for one part holding another.
+///
+/// [`Event::Unknown`]: crate::entry::incremental::Event::Unknown
+///
+/// Each says which file it was and, for a journal, which line, counted from
+/// one as an editor counts them. The event is named wherever the line got far
+/// enough to have one.
+#[derive(Debug)]
+pub enum Error {
+    /// The file could not be opened or read
+    Io {
+        path: PathBuf,
+        /// The line being read when it failed, where there was one
+        line: Option<usize>,
+        source: io::Error,
+    },
+    /// What was read is not JSON
+    Json {
+        path: PathBuf,
+        line: Option<usize>,
+        source: serde_json::Error,
+    },
+    /// The event's fields are not what was expected
+    Event {
+        path: PathBuf,
+        line: Option<usize>,
+        /// [`None`] where the object carries no `event` at all
+        event: Option<String>,
+        source: serde_json::Error,
+    },
+    /// An event the enum being read into has no variant for
+    UnknownEvent {
+        path: PathBuf,
+        line: Option<usize>,
+        event: String,
+    },
+    /// A journal's `Fileheader` gives a different part than its name does
+    ///
+    /// Which puts it somewhere other than where the name sorted it.
+    Part {
+        path: PathBuf,
+        line: Option<usize>,
+        /// The part in the file's name
+        named: u8,
+        /// The part its `Fileheader` gives
+        header: u8,
+    },
+}
+
+impl Error {
+    pub(crate) fn io<P: AsRef<Path>>(
+        path: P,
+        line: Option<usize>,
+        source: io::Error,
+    ) -> Self {
+        Error::Io {
+            path: path.as_ref().to_path_buf(),
+            line,
+            source,
+        }
+    }
+
+    /// Sort out why `text` did not read, having already failed to read it
+    ///
+    /// Read again as bare JSON, which tells a line that is not JSON from one
+    /// that is and does not fit, and finds the `event` it names. That name is
+    /// then looked for among `E`'s variants, where it has a list of them.
+    pub(crate) fn from_json<E, P: AsRef<Path>>(
+        path: P,
+        line: Option<usize>,
+        text: &str,
+        source: serde_json::Error,
+    ) -> Self
+    where
+        for<'de> E: Deserialize<'de>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let value = match serde_json::from_str::<serde_json::Value>(text) {
+            Ok(value) => value,
+            Err(source) => return Error::Json { path, line, source },
+        };
+        let event = value
+            .get("event")
+            .and_then(|event| event.as_str())
+            .map(str::to_string);
+
+        if let (Some(event), Some(variants)) =
+            (&event, crate::de::variants(E::deserialize))
+        {
+            if !variants.contains(&event.as_str()) {
+                return Error::UnknownEvent {
+                    path,
+                    line,
+                    event: event.clone(),
+                };
+            }
+        }
+        Error::Event {
+            path,
+            line,
+            event,
+            source,
+        }
+    }
+
+    /// The file it was reading
+    pub fn path(&self) -> &Path {
+        match self {
+            Error::Io { path, .. }
+            | Error::Json { path, .. }
+            | Error::Event { path, .. }
+            | Error::UnknownEvent { path, .. }
+            | Error::Part { path, .. } => path,
+        }
+    }
+
+    /// The line it was reading, counted from one
+    ///
+    /// [`None`] for a status file, which is one object however many lines the
+    /// game spreads it across.
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Error::Io { line, .. }
+            | Error::Json { line, .. }
+            | Error::Event { line, .. }
+            | Error::UnknownEvent { line, .. }
+            | Error::Part { line, .. } => *line,
+        }
+    }
+
+    /// The event being read, where it got far enough to say
+    pub fn event(&self) -> Option<&str> {
+        match self {
+            Error::Event { event, .. } => event.as_deref(),
+            Error::UnknownEvent { event, .. } => Some(event),
+            Error::Part { .. } => Some("Fileheader"),
+            Error::Io { .. } | Error::Json { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.path().display())?;
+        if let Some(line) = self.line() {
+            write!(f, ":{}", line)?;
+        }
+        match self {
+            Error::Io { source, .. } => write!(f, ": {}", source),
+            Error::Json { source, .. } => {
+                write!(f, ": not JSON: {}", source)
+            }
+            Error::Event {
+                event: Some(event),
+                source,
+                ..
+            } => write!(f, ": {} did not read: {}", event, source),
+            Error::Event {
+                event: None,
+                source,
+                ..
+            } => write!(f, ": no event: {}", source),
+            Error::UnknownEvent { event, .. } => {
+                write!(f, ": {} is not an event read here", event)
+            }
+            Error::Part { named, header, .. } => write!(
+                f,
+                ": named part {}, and its Fileheader says part {}",
+                named, header
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Json { source, .. } | Error::Event { source, .. } => {
+                Some(source)
+            }
+            Error::UnknownEvent { .. } | Error::Part { .. } => None,
+        }
+    }
+}
+
+/// Each of the four, told apart from the others
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entry::testing::scratch_file;
+    use crate::entry::{parse_status_file, Entry, Event, NavRoute};
+    use std::fs;
+
+    /// Read `text` as though it were line `line` of `path`
+    fn read<E>(path: &str, line: usize, text: &str) -> Error
+    where
+        for<'de> E: Deserialize<'de> + fmt::Debug,
+    {
+        let err = serde_json::from_str::<Entry<E>>(text)
+            .expect_err("should not read");
+        Error::from_json::<E, _>(path, Some(line), text, err)
+    }
+
+    /// A missing file was `unwrap`ped, and took the caller down with it
+    #[test]
+    fn a_missing_status_file_is_an_io_error() {
+        let err = parse_status_file::<_, NavRoute>(
+            "/nowhere/elite_journal/NavRoute.json",
+        )
+        .expect_err("a missing file should not read");
+
+        assert!(matches!(err, Error::Io { .. }));
+        assert_eq!(
+            err.path(),
+            Path::new("/nowhere/elite_journal/NavRoute.json")
+        );
+        assert_eq!(err.event(), None);
+    }
+
+    /// Half a line, which is what the game leaves while it is writing
+    #[test]
+    fn half_a_line_is_not_json() {
+        let err = read::<Event>(
+            "Journal.log",
+            7,
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "Sc"#,
+        );
+
+        assert!(matches!(err, Error::Json { .. }));
+        assert_eq!(err.line(), Some(7));
+        assert!(err.to_string().starts_with("Journal.log:7: not JSON"));
+    }
+
+    /// A known event the game has changed under us
+    #[test]
+    fn a_known_event_that_does_not_fit() {
+        let err = read::<Event>(
+            "Journal.log",
+            3,
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Undocked",
+                "StationName": "Ray Gateway"
+            }"#,
+        );
+
+        assert!(matches!(err, Error::Event { .. }));
+        assert_eq!(err.event(), Some("Undocked"));
+        assert!(
+            err.to_string().contains("MarketID"),
+            "said nothing about the missing field: {}",
+            err,
+        );
+    }
+
+    /// An event no variant answers to, where nothing catches the rest
+    ///
+    /// The journal's [`Event`] keeps these as [`Event::Unknown`] and does not
+    /// get here. Anything read without such a catch-all does, and says why.
+    #[test]
+    fn an_event_read_nowhere() {
+        #[derive(Deserialize, Debug)]
+        #[serde(tag = "event")]
+        enum Narrow {
+            Shutdown,
+        }
+
+        let err = read::<Narrow>(
+            "Journal.log",
+            12,
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Music",
+                "MusicTrack": "MainMenu"
+            }"#,
+        );
+
+        assert!(matches!(err, Error::UnknownEvent { .. }));
+        assert_eq!(err.event(), Some("Music"));
+        assert_eq!(err.line(), Some(12));
+        assert!(err.to_string().contains("Music"), "{}", err);
+    }
+
+    /// An event the narrow enum does have, which does not fit, is not unknown
+    ///
+    /// Only an `event` missing from the variants is. A field missing from one
+    /// of them is still the game having changed it.
+    #[test]
+    fn a_narrow_event_that_does_not_fit_is_not_unknown() {
+        #[derive(Deserialize, Debug)]
+        #[serde(tag = "event")]
+        enum Narrow {
+            #[allow(dead_code)]
+            Music {
+                #[serde(rename = "MusicTrack")]
+                track: String,
+            },
+        }
+
+        let err = read::<Narrow>(
+            "Journal.log",
+            12,
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "event": "Music" }"#,
+        );
+
+        assert!(matches!(err, Error::Event { .. }));
+        assert_eq!(err.event(), Some("Music"));
+        assert!(err.to_string().contains("MusicTrack"), "{}", err);
+    }
+
+    /// A known event with a value somewhere inside it that is new
+    ///
+    /// Was taken for an event nothing here reads, as serde words both the
+    /// same, and the value at fault went unsaid.
+    #[test]
+    fn a_known_event_with_a_new_value_inside_it() {
+        let err = read::<Event>(
+            "Journal.log",
+            5,
+            r#"{
+                "timestamp": "2026-08-08T12:00:00Z",
+                "event": "Docked",
+                "StationName": "Ray Gateway",
+                "StationType": "Coriolis",
+                "StarSystem": "Diaguandri",
+                "SystemAddress": 670417429889,
+                "MarketID": 3223343616,
+                "StationGovernment": "$government_Technocracy;",
+                "StationAllegiance": "Independent",
+                "StationEconomy": "$economy_HighTech;",
+                "StationServices": [ "dock" ],
+                "DistFromStarLS": 1500.0
+            }"#,
+        );
+
+        assert!(matches!(err, Error::Event { .. }));
+        assert_eq!(err.event(), Some("Docked"));
+        assert!(
+            err.to_string().contains("Technocracy"),
+            "said nothing of the new value: {}",
+            err,
+        );
+    }
+
+    /// A status file that does not fit names the file and no line
+    #[test]
+    fn a_status_file_that_does_not_fit() {
+        let path = scratch_file(
+            "NavRoute.json",
+            r#"{ "timestamp": "2026-08-08T12:00:00Z", "Route": 7 }"#,
+        );
+        let err = parse_status_file::<_, NavRoute>(&path)
+            .expect_err("a route of seven should not read");
+        fs::remove_file(&path).ok();
+
+        assert!(matches!(err, Error::Event { event: None, .. }));
+        assert_eq!(err.path(), path);
+        assert_eq!(err.line(), None);
+    }
+}
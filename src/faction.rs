@@ -2,13 +2,14 @@ use crate::{de::*, prelude::*};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Faction {
     pub name: String,
     #[serde(rename = "FactionState")]
     #[serde(default)]
     #[serde(deserialize_with = "null_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<State>,
 }
 
@@ -32,32 +33,37 @@ fn faction() {
     assert_eq!(None, faction.state);
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct FactionInfo {
     pub name: String,
     #[serde(rename = "FactionState")]
+    #[serde(default)]
     #[serde(deserialize_with = "null_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<State>,
     pub government: Government,
     pub influence: f32,
     pub allegiance: Allegiance,
+    #[serde(default)]
     #[serde(deserialize_with = "null_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub happiness: Option<Happiness>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub pending_states: Vec<StateTrend>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub active_states: Vec<StateTrend>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub recovering_states: Vec<StateTrend>,
     // EDDN optional only?
     #[serde(rename = "MyReputation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reputation: Option<f64>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub squadron_faction: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub home_system: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub happiest_system: bool,
 }
 
@@ -146,10 +152,7 @@ pub enum State {
 
 impl Nullable for State {
     fn is_null(&self) -> bool {
-        match self {
-            State::None => true,
-            _ => false,
-        }
+        matches!(self, State::None)
     }
 }
 
@@ -209,13 +212,14 @@ fn status() {
     assert!(active > pending && pending > recovering);
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct StateTrend {
     pub state: State,
     // TODO: When is this ever not 0?
     #[serde(default)]
     #[serde(deserialize_with = "zero_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub trend: Option<u64>,
 }
 
@@ -255,7 +259,7 @@ fn state_trend() {
     );
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct FactionConflict {
     /// A conflict's type determines what triggers it and the way it is resolved.
@@ -265,6 +269,7 @@ pub struct FactionConflict {
     /// are active.
     ///
     // TODO: Does this match the faction's states vector?
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<Status>,
     /// The defending faction, which had the higher influence before the conflict.
     pub faction_1: FactionConflictProgress,
@@ -309,7 +314,7 @@ fn conflict_type() {
     assert_eq!(FactionConflictType::Election, election);
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct FactionConflictProgress {
     pub name: String,
@@ -347,10 +352,7 @@ pub enum Happiness {
 
 impl Nullable for Happiness {
     fn is_null(&self) -> bool {
-        match self {
-            Happiness::None => true,
-            _ => false,
-        }
+        matches!(self, Happiness::None)
     }
 }
 
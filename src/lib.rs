@@ -17,6 +17,9 @@
 //! therefor not included in the broader [`entry::Event`] `enum`.
 //!
 //! - Use [`parse_journal_file`] and [`parse_journal_dir`] for `*.log` journal files
+//! - Use [`parse_journal_sessions`] for a directory of them a game session at a time
+//! - Use [`JournalReader`] to read a `*.log` journal file a line at a time
+//! - Use [`JournalTail`] to follow the `*.log` journal the game is writing
 //! - Use [`parse_status_file`] for `*.json` status files
 //!
 //! [rtd]: https://elite-journal.readthedocs.io/en/latest
@@ -27,6 +30,12 @@
 //
 // https://github.com/launchbadge/sqlx/issues/657#issuecomment-774040177
 #![allow(unused_braces)]
+// The tests compare against the game's own figures as written, and say that
+// two values are not ordered by saying neither is greater.
+#![cfg_attr(
+    test,
+    allow(clippy::excessive_precision, clippy::neg_cmp_op_on_partial_ord)
+)]
 
 use self::de::Nullable;
 use serde::{Deserialize, Serialize};
@@ -51,7 +60,8 @@ pub mod prelude;
 /// Journal and status file entries
 pub mod entry;
 pub use self::entry::{
-    parse_journal_dir, parse_journal_file, parse_status_file, Entry,
+    parse_journal_dir, parse_journal_file, parse_journal_sessions,
+    parse_status_file, Entry, JournalReader, JournalTail,
 };
 
 /// A star system, located in static 3D space
@@ -75,6 +85,10 @@ pub mod ship;
 /// Serde helper deserializers
 pub mod de;
 
+/// What went wrong reading a journal or status file
+pub mod error;
+pub use self::error::Error;
+
 /// System and faction's organizational structure
 #[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq)]
 #[cfg_attr(feature = "with-sqlx", derive(sqlx::Type))]
@@ -126,10 +140,7 @@ impl fmt::Display for Government {
 
 impl Nullable for Government {
     fn is_null(&self) -> bool {
-        match self {
-            Government::None => true,
-            _ => false,
-        }
+        matches!(self, Government::None)
     }
 }
 
@@ -197,10 +208,7 @@ impl fmt::Display for Allegiance {
 
 impl Nullable for Allegiance {
     fn is_null(&self) -> bool {
-        match self {
-            Allegiance::None => true,
-            _ => false,
-        }
+        matches!(self, Allegiance::None)
     }
 }
 
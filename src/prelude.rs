@@ -10,11 +10,7 @@ pub use crate::station::{
     StationType,
 };
 pub use crate::system::{
-    Coordinate,
-    Economy,
-    // TODO: finish and expose here.
-    // PowerplayState,
-    Security,
+    Coordinate, Economy, PowerConflictProgress, PowerplayState, Security,
     System,
 };
 pub use crate::{Allegiance, Government};
@@ -4,31 +4,41 @@ use serde::{Deserialize, Serialize};
 use sqlx::postgres::{PgHasArrayType, PgTypeInfo};
 use std::fmt;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct Station {
     #[serde(rename = "DistFromStarLS")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dist_from_star_ls: Option<f64>,
     #[serde(rename = "StationName")]
     pub name: String,
     #[serde(rename = "StationType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ty: Option<StationType>,
     #[serde(rename = "MarketID")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub market_id: Option<i64>,
     #[serde(rename = "LandingPads")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub landing_pads: Option<LandingPads>,
     #[serde(rename = "StationFaction")]
     #[serde(default)]
     #[serde(deserialize_with = "crate::de::empty_map_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub faction: Option<Faction>,
     #[serde(rename = "StationGovernment")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub government: Option<Government>,
     #[serde(rename = "StationAllegiance")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allegiance: Option<Allegiance>,
     #[serde(rename = "StationServices")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub services: Option<Vec<Service>>,
     #[serde(rename = "StationEconomies")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub economies: Option<Vec<EconomyShare>>,
     // NOTE: Should really be Some(false) when parsed locally. EDDN filters this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub wanted: Option<bool>,
 }
 
@@ -78,7 +88,7 @@ impl fmt::Display for StationType {
     }
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub enum PadSize {
     Small,
     Medium,
@@ -1,5 +1,5 @@
 use crate::{de::*, prelude::*};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use std::cmp::Ordering;
 use std::fmt;
 
@@ -11,52 +11,79 @@ use geozero::{
 #[cfg(feature = "with-postgis-sqlx")]
 use std::io::Read;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 #[serde(rename_all = "PascalCase")]
 pub struct System {
     #[serde(rename = "SystemAddress")]
     pub address: i64,
     #[serde(rename = "StarPos")]
+    #[serde(serialize_with = "optional_star_pos")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pos: Option<Coordinate>,
     #[serde(rename = "StarSystem")]
     pub name: String,
 
     #[serde(default)]
     #[serde(deserialize_with = "zero_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub population: Option<u64>,
     #[serde(rename = "SystemSecurity")]
     #[serde(default)]
     #[serde(deserialize_with = "null_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub security: Option<Security>,
 
     #[serde(rename = "SystemGovernment")]
     #[serde(default)]
     #[serde(deserialize_with = "null_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub government: Option<Government>,
     #[serde(rename = "SystemAllegiance")]
     #[serde(default)]
     #[serde(deserialize_with = "null_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub allegiance: Option<Allegiance>,
     #[serde(rename = "SystemEconomy")]
     #[serde(default)]
     #[serde(deserialize_with = "null_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub economy: Option<Economy>,
     #[serde(rename = "SystemSecondEconomy")]
     #[serde(default)]
     #[serde(deserialize_with = "null_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub second_economy: Option<Economy>,
 
     #[serde(rename = "SystemFaction")]
     #[serde(default)]
     #[serde(deserialize_with = "crate::de::empty_map_is_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub controlling_faction: Option<Faction>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub factions: Vec<FactionInfo>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub conflicts: Vec<FactionConflict>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub powers: Option<Vec<Power>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub powerplay_state: Option<PowerplayState>,
+    /// The power holding the system, where one does
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub controlling_power: Option<Power>,
+    /// How far the controlling power is from its next standing or its last,
+    /// as a fraction that runs past 1 and below 0 near either edge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub powerplay_state_control_progress: Option<f64>,
+    /// Merits earned this cycle for the power holding it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub powerplay_state_reinforcement: Option<u64>,
+    /// Merits earned this cycle against the power holding it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub powerplay_state_undermining: Option<u64>,
+    /// How far each power has got towards taking an unheld system
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub powerplay_conflict_progress: Option<Vec<PowerConflictProgress>>,
 }
 
 impl System {
@@ -77,6 +104,11 @@ impl System {
             conflicts: vec![],
             powers: None,
             powerplay_state: None,
+            controlling_power: None,
+            powerplay_state_control_progress: None,
+            powerplay_state_reinforcement: None,
+            powerplay_state_undermining: None,
+            powerplay_conflict_progress: None,
         }
     }
 }
@@ -108,6 +140,50 @@ fn system() {
     assert_eq!(None, system.second_economy);
 }
 
+/// Powerplay as the game writes it for the system jumped into or found in
+#[test]
+fn powerplay() {
+    let system = serde_json::from_str::<System>(
+        r#"
+        {
+            "StarSystem": "Sol",
+            "SystemAddress": 10477373803,
+            "Powers": ["Jerome Archer", "Felicia Winters"],
+            "ControllingPower": "Jerome Archer",
+            "PowerplayState": "Stronghold",
+            "PowerplayStateControlProgress": 0.458,
+            "PowerplayStateReinforcement": 26871,
+            "PowerplayStateUndermining": 4112
+        }
+    "#,
+    )
+    .unwrap();
+    assert_eq!(Some(Power::JeromeArcher), system.controlling_power);
+    assert_eq!(Some(PowerplayState::Stronghold), system.powerplay_state);
+    assert_eq!(Some(0.458), system.powerplay_state_control_progress);
+    assert_eq!(Some(4112), system.powerplay_state_undermining);
+    assert_eq!(None, system.powerplay_conflict_progress);
+
+    let system = serde_json::from_str::<System>(
+        r#"
+        {
+            "StarSystem": "Mbambiva",
+            "SystemAddress": 5068732573057,
+            "Powers": ["Nakato Kaine", "Edmund Mahon"],
+            "PowerplayState": "Unoccupied",
+            "PowerplayConflictProgress": [
+                { "Power": "Nakato Kaine", "ConflictProgress": 0.31 },
+                { "Power": "Edmund Mahon", "ConflictProgress": 0.12 }
+            ]
+        }
+    "#,
+    )
+    .unwrap();
+    let progress = system.powerplay_conflict_progress.unwrap();
+    assert_eq!(Power::NakatoKaine, progress[0].power);
+    assert_eq!(0.12, progress[1].conflict_progress);
+}
+
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
 #[cfg_attr(feature = "with-sqlx", derive(sqlx::Type))]
 #[serde(rename_all = "PascalCase")]
@@ -136,11 +212,7 @@ impl fmt::Display for Security {
 
 impl Nullable for Security {
     fn is_null(&self) -> bool {
-        match self {
-            Security::None => true,
-            Security::Anarchy => true,
-            _ => false,
-        }
+        matches!(self, Security::None | Security::Anarchy)
     }
 }
 
@@ -196,7 +268,15 @@ fn security() {
     assert!(!(anarchy < none));
 }
 
-#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+/// Where a system stands in Powerplay
+///
+/// The first Powerplay's standings and the second's are both here, as both
+/// are in journals still being read. Since the second, a system held by a
+/// power is one of [`PowerplayState::Exploited`],
+/// [`PowerplayState::Fortified`] or [`PowerplayState::Stronghold`], and one
+/// held by none is [`PowerplayState::Unoccupied`] or, where powers are close
+/// to taking it, [`PowerplayState::Contested`].
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "with-sqlx", derive(sqlx::Type))]
 pub enum PowerplayState {
     InPrepareRadius,
@@ -266,6 +346,15 @@ pub enum Power {
     ZeminaTorval,
 }
 
+/// One power's progress towards taking a system nobody holds
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PowerConflictProgress {
+    pub power: Power,
+    /// From 0, and taken at 1
+    pub conflict_progress: f64,
+}
+
 #[test]
 fn power() {
     let read = |json: &str| {
@@ -328,10 +417,7 @@ impl fmt::Display for Economy {
 
 impl Nullable for Economy {
     fn is_null(&self) -> bool {
-        match self {
-            Economy::None => true,
-            _ => false,
-        }
+        matches!(self, Economy::None)
     }
 }
 
@@ -368,6 +454,11 @@ fn economy() {
     assert!(serde_json::from_str::<Economy>(r#""""#).unwrap().is_null());
 }
 
+/// Where a system is, in light years from Sol
+///
+/// Read from an array of three, as the journal writes `StarPos`, or from its
+/// fields by name. Written by name, and as an array only where it is a
+/// `StarPos`.
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Copy)]
 pub struct Coordinate {
     pub x: f64,
@@ -375,6 +466,40 @@ pub struct Coordinate {
     pub z: f64,
 }
 
+/// Write a [`Coordinate`] as the journal writes `StarPos`, an array of three
+pub(crate) fn star_pos<S: Serializer>(
+    pos: &Coordinate,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    [pos.x, pos.y, pos.z].serialize(s)
+}
+
+/// [`star_pos`], for a `StarPos` that is not always given
+pub(crate) fn optional_star_pos<S: Serializer>(
+    pos: &Option<Coordinate>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    pos.map(|pos| [pos.x, pos.y, pos.z]).serialize(s)
+}
+
+#[test]
+fn coordinate() {
+    let sol: Coordinate = serde_json::from_str("[0.0, 0.0, 0.0]").unwrap();
+    let written = serde_json::to_string(&sol).unwrap();
+    assert_eq!(written, r#"{"x":0.0,"y":0.0,"z":0.0}"#);
+    assert_eq!(sol, serde_json::from_str(&written).unwrap());
+
+    // Where it is a `StarPos`, it is written as the journal writes it.
+    let mut system = System::new(10477373803, "Sol");
+    system.pos = Some(sol);
+    let written = serde_json::to_value(&system).unwrap();
+    assert_eq!(written["StarPos"], serde_json::json!([0.0, 0.0, 0.0]));
+
+    let named = r#"{ "x": -22.375, "y": 34.84375, "z": 4.0 }"#;
+    let bootis: Coordinate = serde_json::from_str(named).unwrap();
+    assert_eq!(bootis.y, 34.84375);
+}
+
 impl fmt::Display for Coordinate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "({},{},{})", self.x, self.y, self.z)